@@ -35,6 +35,188 @@ pub enum State {
     On, Off, Unknow
 }
 
+#[derive(Debug, Clone)]
+pub enum FuelType {
+    NotAvailable,   Gasoline,   Methanol,   Ethanol,    Diesel, Lpg,    Cng,    Propane,    Electric,
+    BifuelGasoline, BifuelMethanol, BifuelEthanol,  BifuelLpg,  BifuelCng,  BifuelPropane,  BifuelElectricity,
+    BifuelElectricAndCombustion,    HybridGasoline, HybridEthanol,  HybridDiesel,   HybridElectric,
+    HybridElectricAndCombustion,    HybridRegenerative, BifuelDiesel,   Unknow
+}
+
+#[derive(Debug, Clone)]
+pub enum EmissionRequirements {
+    HeavyDutyEuroIV, HeavyDutyEuroV, HeavyDutyEuroEEV, Value(u8)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OxygenSensorLayout {
+    //
+    // Disposition des capteurs d'oxygène : pid 0x13 (2 banques de 4 capteurs) ou pid 0x1d (4 banques de 2 capteurs)
+    //
+    TwoBanks, FourBanks
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IgnitionType {
+    Spark, Compression
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monitor {
+    Misfire,    FuelSystem, Components,
+    Catalyst,   HeatedCatalyst, EvaporativeSystem,  SecondaryAirSystem, AcRefrigerant,  OxygenSensor,   OxygenSensorHeater,
+    NmhcCatalyst,   NoxScrMonitor,  BoostPressure,  ExhaustGasSensor,   PmFilter,   EgrVvtSystem
+}
+
+#[derive(Debug, Clone)]
+pub enum ControlLoopStatus {
+    Reserved, OpenLoop, ClosedLoop, Fault
+}
+
+// Structs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OxygenSensorLocation {
+    pub bank:   u8,
+    pub sensor: u8
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorTest {
+    pub monitor:   Monitor,
+    pub available: bool,
+    pub complete:  bool
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorStatus {
+    //
+    // État des moniteurs de préparation (readiness)
+    // :field mil:            Témoin de dysfonctionnement allumé
+    // :field dtc_count:      Nombre de DTC confirmés
+    // :field ignition:       Type d'allumage (bit B3), qui détermine la signification des octets C et D
+    // :field continuous:     Moniteurs continus (ratés, carburant, composants)
+    // :field non_continuous: Moniteurs non continus, dépendant du type d'allumage
+    //
+    pub mil:            bool,
+    pub dtc_count:      u8,
+    pub ignition:       IgnitionType,
+    pub continuous:     Vec<MonitorTest>,
+    pub non_continuous: Vec<MonitorTest>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriveCycleMonitorStatus {
+    //
+    // État des moniteurs pendant le cycle de conduite en cours (PID 0x41), sans MIL ni nombre de DTC :
+    // l'octet A de ce PID est réservé
+    // :field ignition:       Type d'allumage (bit B3), qui détermine la signification des octets C et D
+    // :field continuous:     Moniteurs continus (ratés, carburant, composants)
+    // :field non_continuous: Moniteurs non continus, dépendant du type d'allumage
+    //
+    pub ignition:       IgnitionType,
+    pub continuous:     Vec<MonitorTest>,
+    pub non_continuous: Vec<MonitorTest>
+}
+#[derive(Debug, Clone)]
+pub struct MaximumValues {
+    pub equivalence_ratio:        u8,
+    pub oxygen_sensor_voltage:    u8,
+    pub oxygen_sensor_current:    u8,
+    pub intake_manifold_pressure: u16
+}
+
+#[derive(Debug, Clone)]
+pub struct AuxiliaryInputOutput {
+    pub power_take_off_active:          Option<bool>,
+    pub automatic_transmission_in_gear: Option<bool>,
+    pub manual_transmission_in_gear:    Option<bool>,
+    pub glow_plug_lamp_on:              Option<bool>
+}
+
+#[derive(Debug, Clone)]
+pub struct FuelPressureControl {
+    pub commanded_rail_pressure_a: Option<u32>,
+    pub rail_pressure_a:           Option<u32>,
+    pub rail_temperature_a:        Option<i16>,
+    pub commanded_rail_pressure_b: Option<u32>,
+    pub rail_pressure_b:           Option<u32>,
+    pub rail_temperature_b:        Option<i16>
+}
+
+#[derive(Debug, Clone)]
+pub struct BoostPressureControl {
+    pub commanded_pressure_a: Option<f64>,
+    pub pressure_a:           Option<f64>,
+    pub commanded_pressure_b: Option<f64>,
+    pub pressure_b:           Option<f64>,
+    pub status_a:             Option<ControlLoopStatus>,
+    pub status_b:             Option<ControlLoopStatus>
+}
+
+#[derive(Debug, Clone)]
+pub struct VariableGeometryTurboControl {
+    pub commanded_position_a: Option<f64>,
+    pub position_a:           Option<f64>,
+    pub commanded_position_b: Option<f64>,
+    pub position_b:           Option<f64>,
+    pub status_a:             Option<ControlLoopStatus>,
+    pub status_b:             Option<ControlLoopStatus>
+}
+
+#[derive(Debug, Clone)]
+pub struct TurbochargerTemperature {
+    pub compressor_inlet:  Option<i16>,
+    pub compressor_outlet: Option<i16>,
+    pub turbine_inlet:     Option<f64>,
+    pub turbine_outlet:    Option<f64>
+}
+
+#[derive(Debug, Clone)]
+pub struct NteStatus {
+    pub inside_control_area:  bool,
+    pub outside_control_area: bool,
+    pub inside_carve_out:     bool,
+    pub deficiency_active:    bool
+}
+
+#[derive(Debug, Clone)]
+pub struct SupportedData {
+    //
+    // Données précédées d'octets de support : indices des bits de support positionnés (bit 0 de l'octet A = 0,
+    // bit 0 de l'octet B = 8, ...) et octets de données restants, non interprétés
+    //
+    pub supported: Vec<usize>,
+    pub data:      Vec<u8>
+}
+
+#[derive(Debug, Clone)]
+pub struct HybridSystemData {
+    pub charging_state:  Option<u8>,
+    pub battery_voltage: Option<f64>,
+    pub battery_current: Option<f64>
+}
+
+#[derive(Debug, Clone)]
+pub struct DieselExhaustFluidData {
+    pub concentration: Option<f64>,
+    pub temperature:   Option<i16>,
+    pub tank_level:    Option<f64>
+}
+
+#[derive(Debug, Clone)]
+pub struct TransmissionGear {
+    pub gear:  Option<u8>,
+    pub ratio: Option<f64>
+}
+
+#[derive(Debug, Clone)]
+pub struct NoxReagentSystem {
+    pub average_consumption:          Option<f64>,
+    pub average_demanded_consumption: Option<f64>,
+    pub tank_level:                   Option<f64>,
+    pub warning_timer:                Option<u32>
+}
+
 // Fonctions
 pub fn decode_celsius(encoded: u8) -> i16 {
     encoded as i16 - 40
@@ -151,12 +333,19 @@ pub fn decode_auxiliary_input_status(input: u8) -> State {
     }
 }
 
-pub fn decode_seconds(input: u16) -> u16 {
+pub fn decode_word(input: u16) -> u16 {
+    //
+    // Valeur 256*A + B sans mise à l'échelle (secondes, km, minutes, Nm, ...)
+    //
     256*(input>>8) + (input&0xff)
 }
 
+pub fn decode_seconds(input: u16) -> u16 {
+    decode_word(input)
+}
+
 pub fn decode_km(input: u16) -> u16 {
-    256*(input>>8) + (input&0xff)
+    decode_word(input)
 }
 
 pub fn decode_fuel_rail_pressure(input: u16) -> f64 {
@@ -173,11 +362,631 @@ pub fn decode_oxygen_sensor_lambda(input: u32) -> (f64, f64) {
     let c : f64 = ((input >> 8) & 0xff) as f64;
     let d : f64 = (input & 0xff) as f64;
     (
-        (2.0/65536.0) * (256.0*a as f64 + b),
-        (8.0/65536.0) * (256.0*c as f64 + d)
+        (2.0/65536.0) * (256.0*a + b),
+        (8.0/65536.0) * (256.0*c + d)
     )
 }
 
 pub fn decode_egr_error(input: u8) -> f64 {
     input as f64 / 1.28 - 100.0
-}
\ No newline at end of file
+}
+
+pub fn decode_evap_vapor_pressure(input: u16) -> f64 {
+    (input as i16) as f64 / 4.0
+}
+
+pub fn decode_oxygen_sensor_current(input: u32) -> (f64, f64) {
+    let a : f64 = (input >> 24) as f64;
+    let b : f64 = ((input >> 16) & 0xff) as f64;
+    let c : f64 = ((input >> 8) & 0xff) as f64;
+    let d : f64 = (input & 0xff) as f64;
+    (
+        (2.0/65536.0) * (256.0*a + b),
+        (256.0*c + d) / 256.0 - 128.0
+    )
+}
+
+pub fn decode_catalyst_temperature(input: u16) -> f64 {
+    input as f64 / 10.0 - 40.0
+}
+
+pub fn decode_control_module_voltage(input: u16) -> f64 {
+    input as f64 / 1000.0
+}
+
+pub fn decode_absolute_load(input: u16) -> f64 {
+    input as f64 * 100.0 / 255.0
+}
+
+pub fn decode_equivalence_ratio(input: u16) -> f64 {
+    (2.0/65536.0) * input as f64
+}
+
+pub fn decode_maximum_values(input: u32) -> MaximumValues {
+    MaximumValues {
+        equivalence_ratio:        (input >> 24) as u8,
+        oxygen_sensor_voltage:    ((input >> 16) & 0xff) as u8,
+        oxygen_sensor_current:    ((input >> 8) & 0xff) as u8,
+        intake_manifold_pressure: (input & 0xff) as u16 * 10
+    }
+}
+
+pub fn decode_maximum_air_flow(input: u32) -> u16 {
+    (input >> 24) as u16 * 10
+}
+
+pub fn decode_fuel_type(input: u8) -> FuelType {
+    match input {
+        0  => FuelType::NotAvailable,
+        1  => FuelType::Gasoline,
+        2  => FuelType::Methanol,
+        3  => FuelType::Ethanol,
+        4  => FuelType::Diesel,
+        5  => FuelType::Lpg,
+        6  => FuelType::Cng,
+        7  => FuelType::Propane,
+        8  => FuelType::Electric,
+        9  => FuelType::BifuelGasoline,
+        10 => FuelType::BifuelMethanol,
+        11 => FuelType::BifuelEthanol,
+        12 => FuelType::BifuelLpg,
+        13 => FuelType::BifuelCng,
+        14 => FuelType::BifuelPropane,
+        15 => FuelType::BifuelElectricity,
+        16 => FuelType::BifuelElectricAndCombustion,
+        17 => FuelType::HybridGasoline,
+        18 => FuelType::HybridEthanol,
+        19 => FuelType::HybridDiesel,
+        20 => FuelType::HybridElectric,
+        21 => FuelType::HybridElectricAndCombustion,
+        22 => FuelType::HybridRegenerative,
+        23 => FuelType::BifuelDiesel,
+        _  => FuelType::Unknow
+    }
+}
+
+pub fn decode_absolute_evap_pressure(input: u16) -> f64 {
+    input as f64 / 200.0
+}
+
+pub fn decode_evap_vapor_pressure_wide(input: u16) -> i16 {
+    input as i16
+}
+
+pub fn decode_secondary_oxygen_trim(input: u16) -> (f64, f64) {
+    (
+        decode_fuel_trim((input >> 8) as u8),
+        decode_fuel_trim((input & 0xff) as u8)
+    )
+}
+
+pub fn decode_injection_timing(input: u16) -> f64 {
+    input as f64 / 128.0 - 210.0
+}
+
+pub fn decode_fuel_rate(input: u16) -> f64 {
+    input as f64 / 20.0
+}
+
+pub fn decode_emission_requirements(input: u8) -> EmissionRequirements {
+    match input {
+        0x0e => EmissionRequirements::HeavyDutyEuroIV,
+        0x0f => EmissionRequirements::HeavyDutyEuroV,
+        0x10 => EmissionRequirements::HeavyDutyEuroEEV,
+        _    => EmissionRequirements::Value(input)
+    }
+}
+
+fn byte_at(input: &[u8], index: usize) -> u8 {
+    input.get(index).copied().unwrap_or(0)
+}
+
+fn word_at(input: &[u8], index: usize) -> u16 {
+    256*byte_at(input, index) as u16 + byte_at(input, index+1) as u16
+}
+
+fn dword_at(input: &[u8], index: usize) -> u32 {
+    (word_at(input, index) as u32) << 16 | word_at(input, index+2) as u32
+}
+
+fn is_supported(support: u8, bit: usize) -> bool {
+    bit < 8 && support >> bit & 1 != 0
+}
+
+pub fn decode_supported<T>(support: u8, values: Vec<T>) -> Vec<Option<T>> {
+    //
+    // Associe chaque valeur au bit de support correspondant (bit 0 pour la première valeur)
+    //
+    values.into_iter()
+        .enumerate()
+        .map(|(bit, value)| if is_supported(support, bit) { Some(value) } else { None })
+        .collect()
+}
+
+fn decode_supported_pair<T>(support: u8, first: T, second: T) -> (Option<T>, Option<T>) {
+    (
+        if is_supported(support, 0) { Some(first) } else { None },
+        if is_supported(support, 1) { Some(second) } else { None }
+    )
+}
+
+pub fn decode_percent_torque(input: u8) -> i16 {
+    input as i16 - 125
+}
+
+pub fn decode_engine_percent_torque_data(input: Vec<u8>) -> Vec<i16> {
+    input.iter().take(5).map(|b| decode_percent_torque(*b)).collect()
+}
+
+pub fn decode_auxiliary_input_output(input: u16) -> AuxiliaryInputOutput {
+    let support : u8 = (input >> 8) as u8;
+    let status  : u8 = (input & 0xff) as u8;
+    let flag = |bit: usize| if is_supported(support, bit) { Some(is_supported(status, bit)) } else { None };
+    AuxiliaryInputOutput {
+        power_take_off_active:          flag(0),
+        automatic_transmission_in_gear: flag(1),
+        manual_transmission_in_gear:    flag(2),
+        glow_plug_lamp_on:              flag(3)
+    }
+}
+
+pub fn decode_maf_sensors(input: Vec<u8>) -> (Option<f64>, Option<f64>) {
+    decode_supported_pair(byte_at(&input, 0), word_at(&input, 1) as f64 / 32.0, word_at(&input, 3) as f64 / 32.0)
+}
+
+pub fn decode_temperature_sensor_pair(input: Vec<u8>) -> (Option<i16>, Option<i16>) {
+    decode_supported_pair(byte_at(&input, 0), decode_celsius(byte_at(&input, 1)), decode_celsius(byte_at(&input, 2)))
+}
+
+pub fn decode_temperature_sensors(input: Vec<u8>) -> Vec<Option<i16>> {
+    let values : Vec<i16> = input.iter().skip(1).map(|b| decode_celsius(*b)).collect();
+    decode_supported(byte_at(&input, 0), values)
+}
+
+pub fn decode_percent_sensors(input: Vec<u8>) -> Vec<Option<f64>> {
+    let values : Vec<f64> = input.iter().skip(1).map(|b| decode_percent(*b)).collect();
+    decode_supported(byte_at(&input, 0), values)
+}
+
+pub fn decode_egr_sensors(input: Vec<u8>) -> Vec<Option<f64>> {
+    //
+    // Pour chaque vanne (A puis B) : rapport cyclique commandé, rapport cyclique réel, erreur
+    //
+    let values : Vec<f64> = input.iter()
+        .skip(1)
+        .enumerate()
+        .map(|(i, b)| if i % 3 == 2 { decode_egr_error(*b) } else { decode_percent(*b) })
+        .collect();
+    decode_supported(byte_at(&input, 0), values)
+}
+
+pub fn decode_egr_temperatures(input: Vec<u8>) -> Vec<Option<i16>> {
+    //
+    // Les bits 4 à 7 du support indiquent une plage étendue (4 °C par bit) pour chaque capteur
+    //
+    let support : u8 = byte_at(&input, 0);
+    let values : Vec<i16> = input.iter()
+        .skip(1)
+        .enumerate()
+        .map(|(i, b)| if is_supported(support, i+4) { 4 * *b as i16 - 40 } else { decode_celsius(*b) })
+        .collect();
+    decode_supported(support & 0x0f, values)
+}
+
+pub fn decode_fuel_pressure_control(input: Vec<u8>) -> FuelPressureControl {
+    let supported = decode_supported(byte_at(&input, 0), vec![
+        word_at(&input, 1) as u32 * 10, word_at(&input, 3) as u32 * 10, byte_at(&input, 5) as u32,
+        word_at(&input, 6) as u32 * 10, word_at(&input, 8) as u32 * 10, byte_at(&input, 10) as u32
+    ]);
+    FuelPressureControl {
+        commanded_rail_pressure_a: supported[0],
+        rail_pressure_a:           supported[1],
+        rail_temperature_a:        supported[2].map(|t| decode_celsius(t as u8)),
+        commanded_rail_pressure_b: supported[3],
+        rail_pressure_b:           supported[4],
+        rail_temperature_b:        supported[5].map(|t| decode_celsius(t as u8))
+    }
+}
+
+pub fn decode_pressure_sensors(input: Vec<u8>) -> Vec<Option<u32>> {
+    let values : Vec<u32> = (0..(input.len().saturating_sub(1) / 2)).map(|i| word_at(&input, 1 + 2*i) as u32 * 10).collect();
+    decode_supported(byte_at(&input, 0), values)
+}
+
+pub fn decode_turbo_inlet_pressure(input: Vec<u8>) -> (Option<u8>, Option<u8>) {
+    decode_supported_pair(byte_at(&input, 0), byte_at(&input, 1), byte_at(&input, 2))
+}
+
+fn decode_control_loop_status(input: u8) -> ControlLoopStatus {
+    match input & 0x03 {
+        1 => ControlLoopStatus::OpenLoop,
+        2 => ControlLoopStatus::ClosedLoop,
+        3 => ControlLoopStatus::Fault,
+        _ => ControlLoopStatus::Reserved
+    }
+}
+
+pub fn decode_boost_pressure_control(input: Vec<u8>) -> BoostPressureControl {
+    let support   : u8 = byte_at(&input, 0);
+    let status    : u8 = byte_at(&input, 9);
+    let supported = decode_supported(support, (0..4).map(|i| word_at(&input, 1 + 2*i) as f64 / 32.0).collect());
+    BoostPressureControl {
+        commanded_pressure_a: supported[0],
+        pressure_a:           supported[1],
+        commanded_pressure_b: supported[2],
+        pressure_b:           supported[3],
+        status_a:             if is_supported(support, 4) { Some(decode_control_loop_status(status)) } else { None },
+        status_b:             if is_supported(support, 5) { Some(decode_control_loop_status(status >> 2)) } else { None }
+    }
+}
+
+pub fn decode_variable_geometry_turbo_control(input: Vec<u8>) -> VariableGeometryTurboControl {
+    let support   : u8 = byte_at(&input, 0);
+    let status    : u8 = byte_at(&input, 5);
+    let supported = decode_supported(support, (1..5).map(|i| decode_percent(byte_at(&input, i))).collect());
+    VariableGeometryTurboControl {
+        commanded_position_a: supported[0],
+        position_a:           supported[1],
+        commanded_position_b: supported[2],
+        position_b:           supported[3],
+        status_a:             if is_supported(support, 4) { Some(decode_control_loop_status(status)) } else { None },
+        status_b:             if is_supported(support, 5) { Some(decode_control_loop_status(status >> 2)) } else { None }
+    }
+}
+
+pub fn decode_exhaust_pressure(input: Vec<u8>) -> (Option<f64>, Option<f64>) {
+    decode_supported_pair(byte_at(&input, 0), word_at(&input, 1) as f64 / 100.0, word_at(&input, 3) as f64 / 100.0)
+}
+
+pub fn decode_turbocharger_rpm(input: Vec<u8>) -> (Option<u32>, Option<u32>) {
+    //
+    // 10 rpm par bit, soit jusqu'à 655 350 rpm
+    //
+    decode_supported_pair(byte_at(&input, 0), word_at(&input, 1) as u32 * 10, word_at(&input, 3) as u32 * 10)
+}
+
+pub fn decode_turbocharger_temperature(input: Vec<u8>) -> TurbochargerTemperature {
+    let support : u8 = byte_at(&input, 0);
+    TurbochargerTemperature {
+        compressor_inlet:  if is_supported(support, 0) { Some(decode_celsius(byte_at(&input, 1))) } else { None },
+        compressor_outlet: if is_supported(support, 1) { Some(decode_celsius(byte_at(&input, 2))) } else { None },
+        turbine_inlet:     if is_supported(support, 2) { Some(decode_catalyst_temperature(word_at(&input, 3))) } else { None },
+        turbine_outlet:    if is_supported(support, 3) { Some(decode_catalyst_temperature(word_at(&input, 5))) } else { None }
+    }
+}
+
+pub fn decode_wide_temperature_sensors(input: Vec<u8>) -> Vec<Option<f64>> {
+    let values : Vec<f64> = (0..(input.len().saturating_sub(1) / 2)).map(|i| decode_catalyst_temperature(word_at(&input, 1 + 2*i))).collect();
+    decode_supported(byte_at(&input, 0), values)
+}
+
+pub fn decode_particulate_filter_pressure(input: Vec<u8>) -> Vec<Option<f64>> {
+    //
+    // Pression différentielle (signée), pression d'entrée et pression de sortie du filtre
+    //
+    decode_supported(byte_at(&input, 0), vec![
+        (word_at(&input, 1) as i16) as f64 / 100.0,
+        word_at(&input, 3) as f64 / 100.0,
+        word_at(&input, 5) as f64 / 100.0
+    ])
+}
+
+pub fn decode_nte_status(input: u8) -> NteStatus {
+    NteStatus {
+        inside_control_area:  is_supported(input, 0),
+        outside_control_area: is_supported(input, 1),
+        inside_carve_out:     is_supported(input, 2),
+        deficiency_active:    is_supported(input, 3)
+    }
+}
+
+pub fn decode_run_times(input: Vec<u8>) -> Vec<Option<u32>> {
+    let values : Vec<u32> = (0..(input.len().saturating_sub(1) / 4)).map(|i| dword_at(&input, 1 + 4*i)).collect();
+    decode_supported(byte_at(&input, 0), values)
+}
+
+pub fn decode_nox_sensors(input: Vec<u8>) -> Vec<Option<u16>> {
+    let values : Vec<u16> = (0..(input.len().saturating_sub(1) / 2)).map(|i| word_at(&input, 1 + 2*i)).collect();
+    decode_supported(byte_at(&input, 0), values)
+}
+
+pub fn decode_nox_reagent_system(input: Vec<u8>) -> NoxReagentSystem {
+    let support : u8 = byte_at(&input, 0);
+    NoxReagentSystem {
+        average_consumption:          if is_supported(support, 0) { Some(word_at(&input, 1) as f64 * 0.005) } else { None },
+        average_demanded_consumption: if is_supported(support, 1) { Some(word_at(&input, 3) as f64 * 0.005) } else { None },
+        tank_level:                   if is_supported(support, 2) { Some(decode_percent(byte_at(&input, 5))) } else { None },
+        warning_timer:                if is_supported(support, 3) { Some(dword_at(&input, 6)) } else { None }
+    }
+}
+
+pub fn decode_particulate_matter_sensors(input: Vec<u8>) -> (Option<f64>, Option<f64>) {
+    decode_supported_pair(byte_at(&input, 0), word_at(&input, 1) as f64 / 80.0, word_at(&input, 3) as f64 / 80.0)
+}
+
+pub fn decode_intake_manifold_pressure_sensors(input: Vec<u8>) -> (Option<f64>, Option<f64>) {
+    decode_supported_pair(byte_at(&input, 0), word_at(&input, 1) as f64 / 32.0, word_at(&input, 3) as f64 / 32.0)
+}
+
+pub fn decode_support_bytes(input: Vec<u8>, support_size: usize) -> SupportedData {
+    let split     : usize      = support_size.min(input.len());
+    let supported : Vec<usize> = (0..8*split).filter(|bit| is_supported(input[bit / 8], bit % 8)).collect();
+    SupportedData { supported, data: input[split..].to_vec() }
+}
+
+pub fn decode_support_status(input: u16) -> Vec<Option<bool>> {
+    //
+    // Octet A : support de chaque indicateur, octet B : valeur de chaque indicateur
+    //
+    let status : u8 = (input & 0xff) as u8;
+    decode_supported((input >> 8) as u8, (0..8).map(|bit| is_supported(status, bit)).collect())
+}
+
+pub fn decode_hybrid_system_data(input: Vec<u8>) -> HybridSystemData {
+    let support : u8 = byte_at(&input, 0);
+    HybridSystemData {
+        charging_state:  if is_supported(support, 0) { Some(byte_at(&input, 1)) } else { None },
+        battery_voltage: if is_supported(support, 1) { Some(word_at(&input, 2) as f64 / 64.0) } else { None },
+        battery_current: if is_supported(support, 2) { Some((word_at(&input, 4) as i16) as f64 / 10.0) } else { None }
+    }
+}
+
+pub fn decode_diesel_exhaust_fluid(input: u32) -> DieselExhaustFluidData {
+    let bytes   : [u8; 4] = input.to_be_bytes();
+    let support : u8      = bytes[0];
+    DieselExhaustFluidData {
+        concentration: if is_supported(support, 0) { Some(bytes[1] as f64 * 0.25) } else { None },
+        temperature:   if is_supported(support, 1) { Some(decode_celsius(bytes[2])) } else { None },
+        tank_level:    if is_supported(support, 2) { Some(decode_percent(bytes[3])) } else { None }
+    }
+}
+
+pub fn decode_engine_fuel_rates(input: u32) -> (f64, f64) {
+    (
+        (input >> 16) as f64 / 50.0,
+        (input & 0xffff) as f64 / 50.0
+    )
+}
+
+pub fn decode_exhaust_flow_rate(input: u16) -> f64 {
+    input as f64 / 5.0
+}
+
+pub fn decode_cylinder_fuel_rate(input: u16) -> f64 {
+    input as f64 / 32.0
+}
+
+pub fn decode_evap_pressure_sensors(input: Vec<u8>) -> Vec<Option<i16>> {
+    let values : Vec<i16> = (0..(input.len().saturating_sub(1) / 2)).map(|i| word_at(&input, 1 + 2*i) as i16).collect();
+    decode_supported(byte_at(&input, 0), values)
+}
+
+pub fn decode_transmission_gear(input: u32) -> TransmissionGear {
+    let bytes     : [u8; 4] = input.to_be_bytes();
+    let supported : bool    = is_supported(bytes[0], 1);
+    TransmissionGear {
+        gear:  if supported { Some(bytes[1] >> 4) } else { None },
+        ratio: if supported { Some(word_at(&bytes, 2) as f64 / 1000.0) } else { None }
+    }
+}
+
+pub fn decode_diesel_exhaust_fluid_dosing(input: u32) -> Option<f64> {
+    let bytes : [u8; 4] = input.to_be_bytes();
+    if is_supported(bytes[0], 0) { Some(bytes[1] as f64 / 2.0) } else { None }
+}
+
+pub fn decode_abs_disable_switch(input: u32) -> Option<bool> {
+    let bytes : [u8; 4] = input.to_be_bytes();
+    if is_supported(bytes[0], 0) { Some(is_supported(bytes[1], 0)) } else { None }
+}
+
+pub fn decode_odometer(input: u32) -> f64 {
+    input as f64 / 10.0
+}
+
+pub fn decode_word_pair(input: u32) -> (u16, u16) {
+    ((input >> 16) as u16, (input & 0xffff) as u16)
+}
+
+impl Monitor {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Monitor::Misfire            => "misfire",
+            Monitor::FuelSystem         => "fuel_system",
+            Monitor::Components         => "components",
+            Monitor::Catalyst           => "catalyst",
+            Monitor::HeatedCatalyst     => "heated_catalyst",
+            Monitor::EvaporativeSystem  => "evaporative_system",
+            Monitor::SecondaryAirSystem => "secondary_air_system",
+            Monitor::AcRefrigerant      => "ac_refrigerant",
+            Monitor::OxygenSensor       => "oxygen_sensor",
+            Monitor::OxygenSensorHeater => "oxygen_sensor_heater",
+            Monitor::NmhcCatalyst       => "nmhc_catalyst",
+            Monitor::NoxScrMonitor      => "nox_scr_monitor",
+            Monitor::BoostPressure      => "boost_pressure",
+            Monitor::ExhaustGasSensor   => "exhaust_gas_sensor",
+            Monitor::PmFilter           => "pm_filter",
+            Monitor::EgrVvtSystem       => "egr_vvt_system"
+        }
+    }
+}
+
+impl MonitorStatus {
+    pub fn monitors(&self) -> impl Iterator<Item = &MonitorTest> {
+        self.continuous.iter().chain(self.non_continuous.iter())
+    }
+
+    pub fn monitor(&self, monitor: Monitor) -> Option<&MonitorTest> {
+        self.monitors().find(|test| test.monitor == monitor)
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.monitors().all(|test| !test.available || test.complete)
+    }
+}
+
+impl DriveCycleMonitorStatus {
+    pub fn monitors(&self) -> impl Iterator<Item = &MonitorTest> {
+        self.continuous.iter().chain(self.non_continuous.iter())
+    }
+
+    pub fn monitor(&self, monitor: Monitor) -> Option<&MonitorTest> {
+        self.monitors().find(|test| test.monitor == monitor)
+    }
+}
+
+fn decode_monitor_tests(monitors: &[Option<Monitor>], available: u8, incomplete: u8) -> Vec<MonitorTest> {
+    monitors.iter()
+        .enumerate()
+        .filter_map(|(bit, monitor)| monitor.map(|monitor| {
+            let available : bool = is_supported(available, bit);
+            MonitorTest { monitor, available, complete: available && !is_supported(incomplete, bit) }
+        }))
+        .collect()
+}
+
+pub fn decode_monitor_status(input: u32) -> MonitorStatus {
+    //
+    // Octet A : MIL et nombre de DTC, octet B : moniteurs continus (bits 0-2 disponibles, bits 4-6 incomplets)
+    // et type d'allumage (bit 3), octets C et D : disponibilité et incomplétude des moniteurs non continus
+    //
+    let [a, b, c, d] : [u8; 4] = input.to_be_bytes();
+    let ignition : IgnitionType = if is_supported(b, 3) { IgnitionType::Compression } else { IgnitionType::Spark };
+    let non_continuous : [Option<Monitor>; 8] = match ignition {
+        IgnitionType::Spark       => [
+            Some(Monitor::Catalyst),            Some(Monitor::HeatedCatalyst),  Some(Monitor::EvaporativeSystem),
+            Some(Monitor::SecondaryAirSystem),  Some(Monitor::AcRefrigerant),   Some(Monitor::OxygenSensor),
+            Some(Monitor::OxygenSensorHeater),  Some(Monitor::EgrVvtSystem)
+        ],
+        IgnitionType::Compression => [
+            Some(Monitor::NmhcCatalyst),        Some(Monitor::NoxScrMonitor),   None,
+            Some(Monitor::BoostPressure),       None,                           Some(Monitor::ExhaustGasSensor),
+            Some(Monitor::PmFilter),            Some(Monitor::EgrVvtSystem)
+        ]
+    };
+    MonitorStatus {
+        mil:            is_supported(a, 7),
+        dtc_count:      a & 0x7f,
+        ignition,
+        continuous:     decode_monitor_tests(&[Some(Monitor::Misfire), Some(Monitor::FuelSystem), Some(Monitor::Components)], b, b >> 4),
+        non_continuous: decode_monitor_tests(&non_continuous, c, d)
+    }
+}
+
+pub fn decode_drive_cycle_monitor_status(input: u32) -> DriveCycleMonitorStatus {
+    //
+    // Octets B, C et D identiques au PID 0x01, l'octet A est ignoré
+    //
+    let status : MonitorStatus = decode_monitor_status(input & 0x00ff_ffff);
+    DriveCycleMonitorStatus {
+        ignition:       status.ignition,
+        continuous:     status.continuous,
+        non_continuous: status.non_continuous
+    }
+}
+
+impl OxygenSensorLayout {
+    pub fn sensors_per_bank(&self) -> u8 {
+        match self {
+            OxygenSensorLayout::TwoBanks  => 4,
+            OxygenSensorLayout::FourBanks => 2
+        }
+    }
+
+    pub fn location(&self, index: u8) -> Option<OxygenSensorLocation> {
+        //
+        // Position du capteur d'oxygène numéro index (1 à 8, comme OxygenSensor1 à OxygenSensor8)
+        // :return: None pour un numéro hors de cette plage
+        //
+        if !(1..=8).contains(&index) { return None; }
+        let per_bank : u8 = self.sensors_per_bank();
+        Some(OxygenSensorLocation { bank: (index - 1) / per_bank + 1, sensor: (index - 1) % per_bank + 1 })
+    }
+}
+
+impl std::fmt::Display for OxygenSensorLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "B{}S{}", self.bank, self.sensor)
+    }
+}
+
+pub fn decode_oxygen_sensors_present(input: u8, layout: OxygenSensorLayout) -> Vec<OxygenSensorLocation> {
+    (0..8).filter(|bit| is_supported(input, *bit)).filter_map(|bit| layout.location(bit as u8 + 1)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elm327::pids::*;
+
+    fn at(bank: u8, sensor: u8) -> OxygenSensorLocation {
+        OxygenSensorLocation { bank, sensor }
+    }
+
+    #[test]
+    fn oxygen_sensor_locations_follow_the_layout() {
+        let two  : Vec<Option<OxygenSensorLocation>> = (0..=9).map(|i| OxygenSensorLayout::TwoBanks.location(i)).collect();
+        let four : Vec<Option<OxygenSensorLocation>> = (0..=9).map(|i| OxygenSensorLayout::FourBanks.location(i)).collect();
+        assert_eq!(two, vec![None, Some(at(1, 1)), Some(at(1, 2)), Some(at(1, 3)), Some(at(1, 4)),
+            Some(at(2, 1)), Some(at(2, 2)), Some(at(2, 3)), Some(at(2, 4)), None]);
+        assert_eq!(four, vec![None, Some(at(1, 1)), Some(at(1, 2)), Some(at(2, 1)), Some(at(2, 2)),
+            Some(at(3, 1)), Some(at(3, 2)), Some(at(4, 1)), Some(at(4, 2)), None]);
+        assert_eq!(OxygenSensorLayout::FourBanks.location(255), None);
+        assert_eq!(at(3, 2).to_string(), "B3S2");
+
+        assert_eq!(OxygenSensor6::new().location(OxygenSensorLayout::TwoBanks), Some(at(2, 2)));
+        assert_eq!(OxygenSensorLambda6::new().location(OxygenSensorLayout::FourBanks), Some(at(3, 2)));
+    }
+
+    #[test]
+    fn oxygen_sensors_present_13_and_1d() {
+        assert_eq!(OxygenSensorPresent::new().decode(&[0x41, 0x13, 0x33]), Ok(vec![at(1, 1), at(1, 2), at(2, 1), at(2, 2)]));
+        assert_eq!(OxygenSensorPresent4Banks::new().decode(&[0x41, 0x1d, 0x33]), Ok(vec![at(1, 1), at(1, 2), at(3, 1), at(3, 2)]));
+        assert_eq!(OxygenSensorPresent4Banks::new().decode(&[0x41, 0x1d, 0x80]), Ok(vec![at(4, 2)]));
+        assert_eq!(OxygenSensorPresent::new().decode(&[0x41, 0x13, 0x00]), Ok(vec![]));
+    }
+
+    #[test]
+    fn words_are_big_endian() {
+        assert_eq!(decode_word(0x01f4), 500);
+        assert_eq!(decode_km(0xffff), 65535);
+        assert_eq!(EngineReferenceTorque::new().decode(&[0x41, 0x63, 0x01, 0xf4]), Ok(500));
+    }
+
+    #[test]
+    fn spark_monitors_use_bytes_c_and_d() {
+        let status = decode_monitor_status(0x8307_6504);
+        assert!(status.mil);
+        assert_eq!(status.dtc_count, 3);
+        assert_eq!(status.ignition, IgnitionType::Spark);
+        assert!(status.continuous.iter().all(|test| test.available && test.complete));
+        assert_eq!(status.monitor(Monitor::Catalyst), Some(&MonitorTest { monitor: Monitor::Catalyst, available: true, complete: true }));
+        assert_eq!(status.monitor(Monitor::EvaporativeSystem), Some(&MonitorTest { monitor: Monitor::EvaporativeSystem, available: true, complete: false }));
+        assert_eq!(status.monitor(Monitor::HeatedCatalyst).map(|test| test.available), Some(false));
+        assert_eq!(status.monitor(Monitor::NmhcCatalyst), None);
+        assert!(!status.is_ready());
+    }
+
+    #[test]
+    fn compression_monitors_switch_on_bit_b3() {
+        // Même octet C que l'allumage commandé, mais le bit B3 change la signification des bits
+        let status = decode_monitor_status(0x0009_0b02);
+        assert!(!status.mil);
+        assert_eq!(status.dtc_count, 0);
+        assert_eq!(status.ignition, IgnitionType::Compression);
+        assert_eq!(status.non_continuous.len(), 6);
+        assert_eq!(status.monitor(Monitor::Catalyst), None);
+        assert_eq!(status.monitor(Monitor::NmhcCatalyst).map(|test| test.complete), Some(true));
+        assert_eq!(status.monitor(Monitor::NoxScrMonitor).map(|test| test.complete), Some(false));
+        assert_eq!(status.monitor(Monitor::BoostPressure).map(|test| test.complete), Some(true));
+        assert_eq!(status.monitor(Monitor::FuelSystem).map(|test| test.available), Some(false));
+    }
+
+    #[test]
+    fn drive_cycle_status_ignores_reserved_byte_a() {
+        let status = MonitorStatusThisDriveCycle::new().decode(&[0x41, 0x41, 0xff, 0x09, 0x0b, 0x02]).unwrap();
+        assert_eq!(status, decode_drive_cycle_monitor_status(0x0009_0b02));
+        assert_eq!(status.ignition, IgnitionType::Compression);
+        assert_eq!(status.monitor(Monitor::NoxScrMonitor).map(|test| test.complete), Some(false));
+    }
+}
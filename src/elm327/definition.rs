@@ -1,3 +1,297 @@
 // Uses
+use std::convert::TryFrom;
+use std::fmt;
+use crate::elm327::protocol::ObdProtocol;
 
-// Structs
\ No newline at end of file
+// Enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdaptiveTiming {
+    Off, Auto1, Auto2
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AtCommand {
+    //
+    // Commandes AT de l'elm327 (jeu v2.x)
+    //
+    Reset,                          // ATZ
+    WarmStart,                      // ATWS
+    SetDefaults,                    // ATD
+    Echo(bool),                     // ATE0 / ATE1
+    Linefeeds(bool),                // ATL0 / ATL1
+    Spaces(bool),                   // ATS0 / ATS1
+    Headers(bool),                  // ATH0 / ATH1
+    Responses(bool),                // ATR0 / ATR1
+    AllowLongMessages(bool),        // ATAL / ATNL
+    SetProtocol(ObdProtocol),       // ATSP h
+    SetProtocolAuto(ObdProtocol),   // ATSP Ah
+    TryProtocol(ObdProtocol),       // ATTP h
+    TryProtocolAuto(ObdProtocol),   // ATTP Ah
+    DescribeProtocol,               // ATDP
+    DescribeProtocolNumber,         // ATDPN
+    SetTimeout(u8),                 // ATST hh (par pas de 4 ms)
+    AdaptiveTiming(AdaptiveTiming), // ATAT0 / ATAT1 / ATAT2
+    ReadVoltage,                    // ATRV
+    Identify,                       // ATI
+    DeviceDescription,              // AT@1
+    DeviceIdentifier,               // AT@2
+    IgnitionMonitor,                // ATIGN
+    SetHeader11(u16),               // ATSH xyz
+    SetHeader(u8, u8, u8),          // ATSH xxyyzz
+    SetHeader29(u32),               // ATSH wwxxyyzz
+    CanReceiveAddress11(u16),       // ATCRA xyz
+    CanReceiveAddress29(u32),       // ATCRA wwxxyyzz
+    ResetCanReceiveAddress,         // ATCRA
+    CanAutoFormatting(bool),        // ATCAF0 / ATCAF1
+    CanFlowControl(bool),           // ATCFC0 / ATCFC1
+    MonitorAll,                     // ATMA
+    MonitorReceiver(u8),            // ATMR hh
+    MonitorTransmitter(u8),         // ATMT hh
+    BufferDump,                     // ATBD
+    KeyWordChecking(bool),          // ATKW0 / ATKW1
+    SlowInit,                       // ATSI
+    FastInit,                       // ATFI
+    ProtocolClose,                  // ATPC
+    LowPower,                       // ATLP
+    Raw(String)                     // Toute autre commande AT, envoyée telle quelle
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AtReply {
+    Ok,
+    Version(String),
+    Description(String),
+    Voltage(f64),
+    Protocol { automatic: bool, description: String },
+    ProtocolNumber { automatic: bool, protocol: ObdProtocol },
+    Ignition(bool),
+    Monitor(Vec<String>),
+    BufferDump(Vec<u8>),
+    Text(Vec<String>)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AtError {
+    Rejected(String),
+    Invalid(String),
+    UnexpectedReply { command: String, reply: String }
+}
+
+impl fmt::Display for AtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AtError::Rejected(command)                 => write!(f, "Commande refusée par l'adaptateur : {}", command),
+            AtError::Invalid(command)                  => write!(f, "Commande invalide : {}", command),
+            AtError::UnexpectedReply { command, reply } => write!(f, "Réponse inattendue à {} : {:?}", command, reply)
+        }
+    }
+}
+
+impl std::error::Error for AtError {}
+
+// Impls
+impl AtCommand {
+    pub fn command(&self) -> String {
+        //
+        // Méthode retournant la commande telle qu'elle doit être envoyée (sans le '\r' final)
+        //
+        match self {
+            AtCommand::Reset                     => "ATZ".to_string(),
+            AtCommand::WarmStart                 => "ATWS".to_string(),
+            AtCommand::SetDefaults               => "ATD".to_string(),
+            AtCommand::Echo(on)                  => format!("ATE{}", *on as u8),
+            AtCommand::Linefeeds(on)             => format!("ATL{}", *on as u8),
+            AtCommand::Spaces(on)                => format!("ATS{}", *on as u8),
+            AtCommand::Headers(on)               => format!("ATH{}", *on as u8),
+            AtCommand::Responses(on)             => format!("ATR{}", *on as u8),
+            AtCommand::AllowLongMessages(true)   => "ATAL".to_string(),
+            AtCommand::AllowLongMessages(false)  => "ATNL".to_string(),
+            AtCommand::SetProtocol(p)            => format!("ATSP{:X}", p.number()),
+            AtCommand::SetProtocolAuto(p)        => format!("ATSPA{:X}", p.number()),
+            AtCommand::TryProtocol(p)            => format!("ATTP{:X}", p.number()),
+            AtCommand::TryProtocolAuto(p)        => format!("ATTPA{:X}", p.number()),
+            AtCommand::DescribeProtocol          => "ATDP".to_string(),
+            AtCommand::DescribeProtocolNumber    => "ATDPN".to_string(),
+            AtCommand::SetTimeout(t)             => format!("ATST{:02X}", t),
+            AtCommand::AdaptiveTiming(mode)      => format!("ATAT{}", match mode {
+                AdaptiveTiming::Off   => 0,
+                AdaptiveTiming::Auto1 => 1,
+                AdaptiveTiming::Auto2 => 2
+            }),
+            AtCommand::ReadVoltage               => "ATRV".to_string(),
+            AtCommand::Identify                  => "ATI".to_string(),
+            AtCommand::DeviceDescription         => "AT@1".to_string(),
+            AtCommand::DeviceIdentifier          => "AT@2".to_string(),
+            AtCommand::IgnitionMonitor           => "ATIGN".to_string(),
+            AtCommand::SetHeader11(h)            => format!("ATSH{:03X}", h),
+            AtCommand::SetHeader(a, b, c)        => format!("ATSH{:02X}{:02X}{:02X}", a, b, c),
+            AtCommand::SetHeader29(h)            => format!("ATSH{:08X}", h),
+            AtCommand::CanReceiveAddress11(a)    => format!("ATCRA{:03X}", a),
+            AtCommand::CanReceiveAddress29(a)    => format!("ATCRA{:08X}", a),
+            AtCommand::ResetCanReceiveAddress    => "ATCRA".to_string(),
+            AtCommand::CanAutoFormatting(on)     => format!("ATCAF{}", *on as u8),
+            AtCommand::CanFlowControl(on)        => format!("ATCFC{}", *on as u8),
+            AtCommand::MonitorAll                => "ATMA".to_string(),
+            AtCommand::MonitorReceiver(a)        => format!("ATMR{:02X}", a),
+            AtCommand::MonitorTransmitter(a)     => format!("ATMT{:02X}", a),
+            AtCommand::BufferDump                => "ATBD".to_string(),
+            AtCommand::KeyWordChecking(on)       => format!("ATKW{}", *on as u8),
+            AtCommand::SlowInit                  => "ATSI".to_string(),
+            AtCommand::FastInit                  => "ATFI".to_string(),
+            AtCommand::ProtocolClose             => "ATPC".to_string(),
+            AtCommand::LowPower                  => "ATLP".to_string(),
+            AtCommand::Raw(command)              => command.clone()
+        }
+    }
+
+    pub fn check(&self) -> Result<(), AtError> {
+        //
+        // Méthode refusant les commandes sans équivalent sur l'adaptateur avant leur envoi
+        // (ATSPA0 / ATTPA0 : la recherche automatique ne peut pas être son propre protocole de repli ;
+        // identifiants CAN dépassant 11 ou 29 bits)
+        //
+        match self {
+            AtCommand::SetProtocolAuto(ObdProtocol::Automatic) | AtCommand::TryProtocolAuto(ObdProtocol::Automatic) =>
+                Err(AtError::Invalid(self.command())),
+            AtCommand::SetHeader11(id) | AtCommand::CanReceiveAddress11(id) if *id > 0x7ff =>
+                Err(AtError::Invalid(self.command())),
+            AtCommand::SetHeader29(id) | AtCommand::CanReceiveAddress29(id) if *id > 0x1fff_ffff =>
+                Err(AtError::Invalid(self.command())),
+            _ => Ok(())
+        }
+    }
+
+    pub fn parse_reply(&self, reply: &str) -> Result<AtReply, AtError> {
+        //
+        // Méthode interprétant la réponse brute de l'adaptateur à cette commande
+        // :param reply: Texte reçu avant le prompt '>', écho éventuel compris
+        //
+        let command : String      = self.command();
+        let lines   : Vec<String> = reply_lines(&command, reply);
+        let unexpected = || AtError::UnexpectedReply { command: command.clone(), reply: reply.to_string() };
+        if lines.iter().any(|l| l == "?") { return Err(AtError::Rejected(command.clone())); }
+        match self {
+            AtCommand::Reset | AtCommand::WarmStart | AtCommand::Identify => {
+                lines.last().map(|l| AtReply::Version(l.clone())).ok_or_else(unexpected)
+            },
+            AtCommand::DeviceDescription | AtCommand::DeviceIdentifier => {
+                lines.first().map(|l| AtReply::Description(l.clone())).ok_or_else(unexpected)
+            },
+            AtCommand::ReadVoltage => {
+                lines.first()
+                    .and_then(|l| l.trim_end_matches(['V', 'v']).trim().parse::<f64>().ok())
+                    .map(AtReply::Voltage)
+                    .ok_or_else(unexpected)
+            },
+            AtCommand::DescribeProtocol => {
+                let line : &String = lines.first().ok_or_else(unexpected)?;
+                match line.strip_prefix("AUTO") {
+                    Some(rest) => Ok(AtReply::Protocol {
+                        automatic:   true,
+                        description: rest.trim_start_matches([',', ' ']).to_string()
+                    }),
+                    None       => Ok(AtReply::Protocol { automatic: false, description: line.clone() })
+                }
+            },
+            AtCommand::DescribeProtocolNumber => {
+                let line : &String = lines.first().ok_or_else(unexpected)?;
+                let (automatic, digit) : (bool, &str) = match line.len() {
+                    2 if line.starts_with('A') => (true, &line[1..]),
+                    _                          => (false, line.as_str())
+                };
+                u8::from_str_radix(digit, 16).ok()
+                    .and_then(|number| ObdProtocol::try_from(number).ok())
+                    .map(|protocol| AtReply::ProtocolNumber { automatic, protocol })
+                    .ok_or_else(unexpected)
+            },
+            AtCommand::IgnitionMonitor => {
+                match lines.first().map(|l| l.as_str()) {
+                    Some("ON")  => Ok(AtReply::Ignition(true)),
+                    Some("OFF") => Ok(AtReply::Ignition(false)),
+                    _           => Err(unexpected())
+                }
+            },
+            AtCommand::MonitorAll | AtCommand::MonitorReceiver(_) | AtCommand::MonitorTransmitter(_) => {
+                Ok(AtReply::Monitor(lines))
+            },
+            AtCommand::BufferDump => {
+                let mut bytes : Vec<u8> = vec![];
+                for token in lines.iter().flat_map(|l| l.split_whitespace()) {
+                    bytes.push(u8::from_str_radix(token, 16).map_err(|_| unexpected())?);
+                }
+                Ok(AtReply::BufferDump(bytes))
+            },
+            AtCommand::Raw(_) => {
+                if lines.len() == 1 && lines[0] == "OK" { Ok(AtReply::Ok) } else { Ok(AtReply::Text(lines)) }
+            },
+            _ => {
+                if lines.iter().any(|l| l == "OK") { Ok(AtReply::Ok) } else { Err(unexpected()) }
+            }
+        }
+    }
+}
+
+impl fmt::Display for AtCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.command())
+    }
+}
+
+// Fonctions
+pub fn reply_lines(command: &str, reply: &str) -> Vec<String> {
+    //
+    // Découpe une réponse en lignes non vides, sans l'écho de la commande
+    //
+    let normalized : String = command.split_whitespace().collect::<String>().to_uppercase();
+    reply.split(['\r', '\n'])
+        .map(|l| l.trim_matches(|c: char| c.is_whitespace() || c == '\0').to_string())
+        .filter(|l| !l.is_empty())
+        .filter(|l| l.split_whitespace().collect::<String>().to_uppercase() != normalized)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_identifiers_out_of_range_are_rejected() {
+        assert_eq!(AtCommand::SetHeader11(0x7df).command(), "ATSH7DF");
+        assert_eq!(AtCommand::SetHeader11(0x7df).check(), Ok(()));
+        assert_eq!(AtCommand::SetHeader11(0x800).check(), Err(AtError::Invalid("ATSH800".to_string())));
+        assert_eq!(AtCommand::CanReceiveAddress11(0x7e8).check(), Ok(()));
+        assert_eq!(AtCommand::CanReceiveAddress11(0xffff).check(), Err(AtError::Invalid("ATCRAFFFF".to_string())));
+        assert_eq!(AtCommand::SetHeader29(0x18db33f1).command(), "ATSH18DB33F1");
+        assert_eq!(AtCommand::SetHeader29(0x1fff_ffff).check(), Ok(()));
+        assert_eq!(AtCommand::SetHeader29(0x2000_0000).check(), Err(AtError::Invalid("ATSH20000000".to_string())));
+        assert_eq!(AtCommand::CanReceiveAddress29(0xffff_ffff).check(), Err(AtError::Invalid("ATCRAFFFFFFFF".to_string())));
+        assert_eq!(AtCommand::SetHeader(0x68, 0x6a, 0xf1).command(), "ATSH686AF1");
+    }
+
+    #[test]
+    fn replies_are_parsed_without_echo() {
+        assert_eq!(AtCommand::Reset.parse_reply("ATZ\r\r\rELM327 v1.5\r\r"), Ok(AtReply::Version("ELM327 v1.5".to_string())));
+        assert_eq!(AtCommand::DeviceDescription.parse_reply("OBDII to RS232 Interpreter\r\r"),
+            Ok(AtReply::Description("OBDII to RS232 Interpreter".to_string())));
+        assert_eq!(AtCommand::ReadVoltage.parse_reply("ATRV\r12.6V\r\r"), Ok(AtReply::Voltage(12.6)));
+        assert_eq!(AtCommand::DescribeProtocol.parse_reply("AUTO, ISO 15765-4 (CAN 11/500)\r\r"),
+            Ok(AtReply::Protocol { automatic: true, description: "ISO 15765-4 (CAN 11/500)".to_string() }));
+        assert_eq!(AtCommand::DescribeProtocol.parse_reply("SAE J1850 PWM\r\r"),
+            Ok(AtReply::Protocol { automatic: false, description: "SAE J1850 PWM".to_string() }));
+        assert_eq!(AtCommand::IgnitionMonitor.parse_reply("OFF\r\r"), Ok(AtReply::Ignition(false)));
+        assert_eq!(AtCommand::BufferDump.parse_reply("10 41 0D 32\r\r"), Ok(AtReply::BufferDump(vec![0x10, 0x41, 0x0d, 0x32])));
+        assert_eq!(AtCommand::Headers(true).parse_reply("ATH1\rOK\r\r"), Ok(AtReply::Ok));
+        assert_eq!(AtCommand::Raw("ATPPS".to_string()).parse_reply("00:FF F\r01:FF F\r\r"),
+            Ok(AtReply::Text(vec!["00:FF F".to_string(), "01:FF F".to_string()])));
+    }
+
+    #[test]
+    fn unexpected_replies_are_errors() {
+        assert_eq!(AtCommand::MonitorReceiver(0x10).parse_reply("?\r\r"), Err(AtError::Rejected("ATMR10".to_string())));
+        assert_eq!(AtCommand::ReadVoltage.parse_reply("ATRV\r\r"),
+            Err(AtError::UnexpectedReply { command: "ATRV".to_string(), reply: "ATRV\r\r".to_string() }));
+        assert!(AtCommand::IgnitionMonitor.parse_reply("MAYBE\r\r").is_err());
+        assert!(AtCommand::BufferDump.parse_reply("10 4G\r\r").is_err());
+        assert!(AtCommand::Echo(false).parse_reply("\r\r").is_err());
+    }
+}
@@ -0,0 +1,278 @@
+// Uses
+use std::fmt;
+use std::str::FromStr;
+use crate::elm327::pids::PidError;
+use crate::elm327::types::*;
+
+// Enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DtcSystem {
+    Powertrain, Chassis, Body, Network
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DtcCategory {
+    //
+    // Stored    : codes mémorisés, ayant allumé le voyant (mode 0x03)
+    // Pending   : codes en attente de confirmation sur le cycle en cours ou le précédent (mode 0x07)
+    // Permanent : codes ne pouvant être effacés que par le calculateur lui-même (mode 0x0a)
+    //
+    Stored, Pending, Permanent
+}
+
+// Structs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDtc(pub String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dtc {
+    //
+    // Code défaut (DTC) obd2, par exemple P0301
+    // :field system: Système concerné (lettre P, C, B ou U)
+    // :field code:   Les quatre chiffres hexadécimaux suivant la lettre, le premier valant 0 à 3
+    //
+    system: DtcSystem,
+    code:   u16
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReportedDtc {
+    //
+    // Code défaut accompagné de sa catégorie et du calculateur l'ayant remonté
+    //
+    pub ecu:      EcuAddress,
+    pub category: DtcCategory,
+    pub dtc:      Dtc
+}
+
+impl fmt::Display for InvalidDtc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Code défaut invalide : {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDtc {}
+
+impl DtcSystem {
+    pub fn letter(&self) -> char {
+        match self {
+            DtcSystem::Powertrain => 'P',
+            DtcSystem::Chassis    => 'C',
+            DtcSystem::Body       => 'B',
+            DtcSystem::Network    => 'U'
+        }
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0x03 {
+            0 => DtcSystem::Powertrain,
+            1 => DtcSystem::Chassis,
+            2 => DtcSystem::Body,
+            _ => DtcSystem::Network
+        }
+    }
+
+    fn bits(&self) -> u8 {
+        match self {
+            DtcSystem::Powertrain => 0,
+            DtcSystem::Chassis    => 1,
+            DtcSystem::Body       => 2,
+            DtcSystem::Network    => 3
+        }
+    }
+}
+
+impl DtcCategory {
+    pub fn mode(&self) -> u8 {
+        match self {
+            DtcCategory::Stored    => 0x03,
+            DtcCategory::Pending   => 0x07,
+            DtcCategory::Permanent => 0x0a
+        }
+    }
+
+    pub fn all() -> [DtcCategory; 3] {
+        [DtcCategory::Stored, DtcCategory::Pending, DtcCategory::Permanent]
+    }
+}
+
+impl fmt::Display for DtcCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DtcCategory::Stored    => write!(f, "Mémorisé"),
+            DtcCategory::Pending   => write!(f, "En attente"),
+            DtcCategory::Permanent => write!(f, "Permanent")
+        }
+    }
+}
+
+impl Dtc {
+    pub fn new(system: DtcSystem, code: u16) -> Self {
+        Dtc { system, code: code & 0x3fff }
+    }
+
+    pub fn from_bytes(a: u8, b: u8) -> Option<Self> {
+        //
+        // Décode l'encodage sur deux octets : bits 7-6 de A pour le système, le reste pour les quatre chiffres
+        // :return: None pour 0x0000, utilisé comme remplissage
+        //
+        if a == 0 && b == 0 { return None; }
+        Some(Dtc { system: DtcSystem::from_bits(a >> 6), code: ((a & 0x3f) as u16) << 8 | b as u16 })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 2] {
+        [self.system.bits() << 6 | (self.code >> 8) as u8, (self.code & 0xff) as u8]
+    }
+
+    pub fn system(&self) -> DtcSystem {
+        self.system
+    }
+
+    pub fn code(&self) -> u16 {
+        self.code
+    }
+
+    pub fn is_generic(&self) -> bool {
+        //
+        // Les codes dont le premier chiffre vaut 0 (et 2 pour P) sont définis par la norme, les autres par le constructeur
+        //
+        match self.code >> 12 {
+            0 => true,
+            2 => self.system == DtcSystem::Powertrain,
+            _ => false
+        }
+    }
+}
+
+impl fmt::Display for Dtc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{:04X}", self.system.letter(), self.code)
+    }
+}
+
+impl FromStr for Dtc {
+    type Err = InvalidDtc;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidDtc(s.to_string());
+        let mut chars = s.trim().chars();
+        let system : DtcSystem = match chars.next().map(|c| c.to_ascii_uppercase()) {
+            Some('P') => DtcSystem::Powertrain,
+            Some('C') => DtcSystem::Chassis,
+            Some('B') => DtcSystem::Body,
+            Some('U') => DtcSystem::Network,
+            _         => return Err(invalid())
+        };
+        let digits : &str = chars.as_str();
+        if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) { return Err(invalid()); }
+        let code : u16 = u16::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        if code > 0x3fff { return Err(invalid()); }
+        Ok(Dtc { system, code })
+    }
+}
+
+impl fmt::Display for ReportedDtc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}, calculateur {})", self.dtc, self.category, self.ecu)
+    }
+}
+
+// Fonctions
+pub fn decode_dtcs(mode: u8, response: &[u8], can: bool) -> Result<Vec<Dtc>, PidError> {
+    //
+    // Décode une réponse aux modes 0x03, 0x07 ou 0x0a
+    // :param mode:     Mode de la requête
+    // :param response: Message complet, mode + 0x40 compris
+    // :param can:      Sur CAN le mode est suivi du nombre de DTC, les autres protocoles complètent par des 0x0000
+    //
+    let received : u8 = *response.first().ok_or(PidError::Empty)?;
+    if received == 0x7f {
+        return Err(PidError::NegativeResponse {
+            mode: response.get(1).copied().unwrap_or(mode),
+            code: response.get(2).copied().unwrap_or(0)
+        });
+    }
+    if received != mode + 0x40 { return Err(PidError::ModeMismatch { expected: mode + 0x40, received }); }
+
+    let (count, data) : (Option<usize>, &[u8]) = if can {
+        let count : u8 = *response.get(1).ok_or(PidError::TooShort { expected: 2, received: response.len() })?;
+        (Some(count as usize), &response[2..])
+    } else {
+        (None, &response[1..])
+    };
+
+    // Un octet isolé en fin de message est un code tronqué
+    if data.len() % 2 != 0 { return Err(PidError::TooShort { expected: response.len() + 1, received: response.len() }); }
+
+    let dtcs : Vec<Dtc> = data.chunks(2)
+        .filter_map(|pair| Dtc::from_bytes(pair[0], pair[1]))
+        .collect();
+    if let Some(count) = count {
+        if dtcs.len() < count { return Err(PidError::TooShort { expected: 2 + 2*count, received: response.len() }); }
+        return Ok(dtcs.into_iter().take(count).collect());
+    }
+    Ok(dtcs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dtc(code: &str) -> Dtc {
+        code.parse().unwrap()
+    }
+
+    #[test]
+    fn dtc_strings_round_trip() {
+        for code in ["P0301", "C0035", "B1A2F", "U0100", "P3FFF"].iter() {
+            let parsed : Dtc = dtc(code);
+            assert_eq!(parsed.to_string(), *code);
+            let [a, b] : [u8; 2] = parsed.to_bytes();
+            assert_eq!(Dtc::from_bytes(a, b), Some(parsed));
+        }
+        assert_eq!(dtc(" p0301 "), Dtc::new(DtcSystem::Powertrain, 0x0301));
+        assert_eq!(Dtc::from_bytes(0xc1, 0x00), Some(dtc("U0100")));
+        assert_eq!(Dtc::from_bytes(0x00, 0x00), None);
+        for invalid in ["", "P030", "P03011", "X0301", "P4000", "P03G1"].iter() {
+            assert_eq!(invalid.parse::<Dtc>(), Err(InvalidDtc(invalid.to_string())));
+        }
+    }
+
+    #[test]
+    fn can_responses_start_with_a_count() {
+        assert_eq!(decode_dtcs(0x03, &[0x43, 0x02, 0x01, 0x33, 0xc1, 0x00], true), Ok(vec![dtc("P0133"), dtc("U0100")]));
+        assert_eq!(decode_dtcs(0x03, &[0x43, 0x00], true), Ok(vec![]));
+        // Les octets au-delà du nombre annoncé sont ignorés
+        assert_eq!(decode_dtcs(0x07, &[0x47, 0x01, 0x01, 0x33, 0x01, 0x34], true), Ok(vec![dtc("P0133")]));
+        assert_eq!(decode_dtcs(0x03, &[0x43, 0x02, 0x01, 0x33], true), Err(PidError::TooShort { expected: 6, received: 4 }));
+        assert_eq!(decode_dtcs(0x03, &[0x43], true), Err(PidError::TooShort { expected: 2, received: 1 }));
+    }
+
+    #[test]
+    fn other_protocols_pad_with_zero_codes() {
+        assert_eq!(decode_dtcs(0x03, &[0x43, 0x01, 0x33, 0x00, 0x00, 0x00, 0x00], false), Ok(vec![dtc("P0133")]));
+        assert_eq!(decode_dtcs(0x0a, &[0x4a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], false), Ok(vec![]));
+        assert_eq!(decode_dtcs(0x03, &[0x43, 0x01, 0x33, 0x41, 0x23, 0x81, 0x00], false), Ok(vec![dtc("P0133"), dtc("C0123"), dtc("B0100")]));
+    }
+
+    #[test]
+    fn odd_trailing_byte_is_an_error() {
+        assert_eq!(decode_dtcs(0x03, &[0x43, 0x01, 0x33, 0x01], false), Err(PidError::TooShort { expected: 5, received: 4 }));
+        assert_eq!(decode_dtcs(0x03, &[0x43, 0x02, 0x01, 0x33, 0x01], true), Err(PidError::TooShort { expected: 6, received: 5 }));
+    }
+
+    #[test]
+    fn multi_frame_responses_hold_many_codes() {
+        let mut response : Vec<u8> = vec![0x43, 0x08];
+        for code in 0x0130..0x0138u16 { response.extend_from_slice(&code.to_be_bytes()); }
+        let dtcs : Vec<Dtc> = decode_dtcs(0x03, &response, true).unwrap();
+        assert_eq!(dtcs.len(), 8);
+        assert_eq!((dtcs[0], dtcs[7]), (dtc("P0130"), dtc("P0137")));
+    }
+
+    #[test]
+    fn negative_and_mismatched_responses() {
+        assert_eq!(decode_dtcs(0x0a, &[0x7f, 0x0a, 0x11], true), Err(PidError::NegativeResponse { mode: 0x0a, code: 0x11 }));
+        assert_eq!(decode_dtcs(0x07, &[0x43, 0x00], true), Err(PidError::ModeMismatch { expected: 0x47, received: 0x43 }));
+        assert_eq!(decode_dtcs(0x03, &[], true), Err(PidError::Empty));
+    }
+}
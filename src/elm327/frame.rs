@@ -0,0 +1,235 @@
+// Uses
+use std::fmt;
+use crate::elm327::definition::reply_lines;
+use crate::elm327::isotp::Reassembler;
+use crate::elm327::protocol::*;
+use crate::elm327::response::*;
+use crate::elm327::types::*;
+
+// Enums
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameError {
+    TooShort(String),
+    InvalidHex(String),
+    Checksum { line: String, expected: u8, received: u8 },
+    UnknownProtocol(ObdProtocol)
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FrameError::TooShort(l)                     => write!(f, "Trame trop courte : {:?}", l),
+            FrameError::InvalidHex(l)                   => write!(f, "Trame illisible : {:?}", l),
+            FrameError::Checksum { line, expected, received } =>
+                write!(f, "Somme de contrôle invalide (0x{:02x} reçue, 0x{:02x} attendue) : {:?}", received, expected, line),
+            FrameError::UnknownProtocol(p)              => write!(f, "Format d'entête inconnu pour le protocole {}", p)
+        }
+    }
+}
+
+impl std::error::Error for FrameError {}
+
+// Structs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameHeader {
+    //
+    // Entête d'une trame reçue
+    // :field priority: Priorité (CAN 29 bits, J1850), absente pour les autres protocoles
+    // :field target:   Adresse cible, en général l'outil de diagnostic (0xf1)
+    // :field source:   Calculateur émetteur : identifiant complet en CAN 11 bits (0x7e8), octet source sinon
+    //
+    pub priority: Option<u8>,
+    pub target:   Option<u8>,
+    pub source:   EcuAddress
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    //
+    // Trame débarrassée de son entête et de sa somme de contrôle
+    // :field data: Octets de données, octet PCI compris sur CAN (hors J1939)
+    //
+    pub header: FrameHeader,
+    pub data:   Vec<u8>
+}
+
+impl fmt::Display for FrameHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0x{:02x}", self.source)?;
+        if let Some(target) = self.target { write!(f, " -> 0x{:02x}", target)?; }
+        if let Some(priority) = self.priority { write!(f, " (priorité {})", priority)?; }
+        Ok(())
+    }
+}
+
+// Fonctions
+pub fn j1850_crc(bytes: &[u8]) -> u8 {
+    //
+    // CRC-8 SAE J1850 : polynôme 0x1d, valeur initiale 0xff, résultat inversé
+    //
+    let mut crc : u8 = 0xff;
+    for byte in bytes.iter() {
+        crc ^= *byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 { (crc << 1) ^ 0x1d } else { crc << 1 };
+        }
+    }
+    !crc
+}
+
+pub fn iso_checksum(bytes: &[u8]) -> u8 {
+    //
+    // Somme de contrôle ISO 9141-2 / ISO 14230-4 : somme des octets modulo 256
+    //
+    bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+pub fn parse_frame(protocol: ObdProtocol, line: &str) -> Result<Frame, FrameError> {
+    //
+    // Découpe une ligne reçue avec les entêtes affichés, avec ou sans espaces (ATS0)
+    // Longueur d'entête et somme de contrôle sont celles du protocole (ObdProtocol::header_len, ObdProtocol::checksum)
+    //
+    let digits : String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    let too_short = || FrameError::TooShort(line.to_string());
+    let invalid   = || FrameError::InvalidHex(line.to_string());
+
+    let header_len : usize = protocol.header_len().ok_or(FrameError::UnknownProtocol(protocol))?;
+    let header     : &str  = digits.get(..header_len).ok_or_else(too_short)?;
+    if !header.bytes().all(|b| b.is_ascii_hexdigit()) { return Err(invalid()); }
+    let id       : u32     = u32::from_str_radix(header, 16).map_err(|_| invalid())?;
+    let mut data : Vec<u8> = match &digits[header_len..] {
+        ""   => vec![],
+        rest => decode_hex(rest).ok_or_else(invalid)?
+    };
+
+    // ISO 14230-4 : octet de longueur après l'adresse source quand l'octet de format ne la porte pas
+    let kwp         : bool  = matches!(protocol, ObdProtocol::Iso14230Kwp5Baud | ObdProtocol::Iso14230KwpFast);
+    let length_byte : usize = if kwp && (id >> 16) & 0x3f == 0 { 1 } else { 0 };
+    if data.len() < 1 + length_byte + protocol.checksum().map_or(0, |_| 1) { return Err(too_short()); }
+
+    if let Some(checksum) = protocol.checksum() {
+        let received : u8 = data.pop().unwrap_or_default();
+        let mut content : Vec<u8> = id.to_be_bytes()[1..].to_vec();
+        content.extend_from_slice(&data);
+        let expected : u8 = match checksum {
+            FrameChecksum::Crc8 => j1850_crc(&content),
+            FrameChecksum::Sum  => iso_checksum(&content)
+        };
+        if expected != received {
+            return Err(FrameError::Checksum { line: line.to_string(), expected, received });
+        }
+    }
+    data.drain(..length_byte);
+
+    // Priorité : bits 26 à 28 de l'identifiant CAN 29 bits, 3 bits de poids fort de l'entête J1850
+    let header : FrameHeader = if protocol.is_extended_can() {
+        FrameHeader { priority: Some(((id >> 26) & 0x07) as u8), target: Some((id >> 8) as u8), source: id & 0xff }
+    } else if protocol.is_can() {
+        FrameHeader { priority: None, target: None, source: id }
+    } else {
+        let priority : Option<u8> = if protocol.checksum() == Some(FrameChecksum::Crc8) { Some((id >> 21) as u8) } else { None };
+        FrameHeader { priority, target: Some((id >> 8) as u8), source: id & 0xff }
+    };
+    Ok(Frame { header, data })
+}
+
+pub fn parse_frames(protocol: ObdProtocol, command: &str, raw: &str) -> Result<Vec<EcuResponse>, Elm327Error> {
+    //
+    // Transforme la réponse brute de l'adaptateur (entêtes affichés) en messages attribués à leur calculateur
+    // Sur CAN les trames sont réassemblées par calculateur, même entrelacées ; une erreur de réassemblage
+    // n'écarte que le message du calculateur concerné, les autres accompagnant l'erreur retournée
+    //
+    let mut reassembler : Reassembler = Reassembler::new();
+
+    for line in reply_lines(command, raw).iter() {
+        if is_information(line) { continue; }
+        if let Some(error) = decode_error(line) { return Err(error); }
+
+        let frame : Frame = parse_frame(protocol, line)?;
+        if protocol.uses_isotp() {
+            let _ = reassembler.push_frame(frame.header.source, &frame.data);
+        } else {
+            reassembler.single(frame.header.source, frame.data);
+        }
+    }
+
+    finish_reassembly(reassembler)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(priority: Option<u8>, target: Option<u8>, source: EcuAddress) -> FrameHeader {
+        FrameHeader { priority, target, source }
+    }
+
+    #[test]
+    fn checksums_match_reference_values() {
+        //
+        // CRC-8/SAE-J1850 : valeur de contrôle publiée pour "123456789"
+        //
+        assert_eq!(j1850_crc(b"123456789"), 0x4b);
+        assert_eq!(j1850_crc(&[]), 0x00);
+        assert_eq!(j1850_crc(&[0x41, 0x6b, 0x10, 0x41, 0x00, 0xbe, 0x1f, 0xb8, 0x10]), 0x32);
+        assert_eq!(iso_checksum(&[0x48, 0x6b, 0x11, 0x41, 0x00, 0xbe, 0x1f, 0xb8, 0x10]), 0xaa);
+    }
+
+    #[test]
+    fn can_headers() {
+        let frame : Frame = parse_frame(ObdProtocol::Iso15765Can11Bit500, "7E8 06 41 00 BE 3F A8 13 00").unwrap();
+        assert_eq!(frame, Frame { header: header(None, None, 0x7e8), data: vec![0x06, 0x41, 0x00, 0xbe, 0x3f, 0xa8, 0x13, 0x00] });
+        assert_eq!(parse_frame(ObdProtocol::Iso15765Can11Bit500, "7E8064100BE3FA81300"), Ok(frame));
+
+        let frame : Frame = parse_frame(ObdProtocol::Iso15765Can29Bit500, "18 DA F1 10 03 41 0D 32 00 00 00 00").unwrap();
+        assert_eq!(frame, Frame { header: header(Some(6), Some(0xf1), 0x10), data: vec![0x03, 0x41, 0x0d, 0x32, 0x00, 0x00, 0x00, 0x00] });
+
+        assert_eq!(parse_frame(ObdProtocol::Iso15765Can11Bit500, "7E"), Err(FrameError::TooShort("7E".to_string())));
+        assert_eq!(parse_frame(ObdProtocol::Iso15765Can29Bit500, "18 DA F1 10"), Err(FrameError::TooShort("18 DA F1 10".to_string())));
+        assert_eq!(parse_frame(ObdProtocol::Automatic, "7E8 03 41 0D 32"), Err(FrameError::UnknownProtocol(ObdProtocol::Automatic)));
+    }
+
+    #[test]
+    fn j1850_headers_and_crc() {
+        let pwm : Frame = parse_frame(ObdProtocol::SaeJ1850Pwm, "41 6B 10 41 00 BE 1F B8 10 32").unwrap();
+        assert_eq!(pwm, Frame { header: header(Some(2), Some(0x6b), 0x10), data: vec![0x41, 0x00, 0xbe, 0x1f, 0xb8, 0x10] });
+
+        let vpw : Frame = parse_frame(ObdProtocol::SaeJ1850Vpw, "48 6B 10 41 0C 0F A0 51").unwrap();
+        assert_eq!(vpw, Frame { header: header(Some(2), Some(0x6b), 0x10), data: vec![0x41, 0x0c, 0x0f, 0xa0] });
+
+        let line : &str = "41 6B 10 41 00 BE 1F B8 10 33";
+        assert_eq!(parse_frame(ObdProtocol::SaeJ1850Pwm, line),
+            Err(FrameError::Checksum { line: line.to_string(), expected: 0x32, received: 0x33 }));
+    }
+
+    #[test]
+    fn iso9141_checksum() {
+        let frame : Frame = parse_frame(ObdProtocol::Iso9141, "48 6B 11 41 00 BE 1F B8 10 AA").unwrap();
+        assert_eq!(frame, Frame { header: header(None, Some(0x6b), 0x11), data: vec![0x41, 0x00, 0xbe, 0x1f, 0xb8, 0x10] });
+
+        let line : &str = "48 6B 11 41 00 BE 1F B8 10 A9";
+        assert_eq!(parse_frame(ObdProtocol::Iso9141, line),
+            Err(FrameError::Checksum { line: line.to_string(), expected: 0xaa, received: 0xa9 }));
+    }
+
+    #[test]
+    fn kwp_three_and_four_byte_headers() {
+        //
+        // Longueur dans l'octet de format (0x83), ou octet de longueur séparé quand format & 0x3f vaut 0 (0x80, 0xc0)
+        //
+        let data : Vec<u8> = vec![0x41, 0x0d, 0x32];
+        for line in ["83 F1 11 41 0D 32 05", "80 F1 11 03 41 0D 32 05", "C0 F1 11 03 41 0D 32 45"].iter() {
+            let frame : Frame = parse_frame(ObdProtocol::Iso14230KwpFast, line).unwrap();
+            assert_eq!(frame, Frame { header: header(None, Some(0xf1), 0x11), data: data.clone() }, "{}", line);
+        }
+        assert_eq!(parse_frame(ObdProtocol::Iso14230KwpFast, "80 F1 11 03 05"), Err(FrameError::TooShort("80 F1 11 03 05".to_string())));
+    }
+
+    #[test]
+    fn frames_are_grouped_by_source() {
+        let raw : &str = "48 6B 10 41 0D 32 43\r48 6B 11 41 00 BE 1F B8 10 AA\r\r";
+        let messages : Vec<EcuResponse> = parse_frames(ObdProtocol::Iso9141, "010D", raw).unwrap();
+        assert_eq!(messages.iter().map(|m| m.ecu).collect::<Vec<_>>(), vec![0x10, 0x11]);
+        assert_eq!(messages[0].data, vec![0x41, 0x0d, 0x32]);
+    }
+}
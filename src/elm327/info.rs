@@ -0,0 +1,381 @@
+// Uses
+use std::fmt;
+use crate::elm327::decoder::IgnitionType;
+use crate::elm327::pids::{check_response, PidError, ResultSize};
+
+// Constantes
+const SPARK_GROUPS : [MonitorGroup; 13] = [
+    MonitorGroup::CatalystBank1, MonitorGroup::CatalystBank2, MonitorGroup::OxygenSensorBank1, MonitorGroup::OxygenSensorBank2,
+    MonitorGroup::EgrVvt, MonitorGroup::SecondaryAir, MonitorGroup::EvaporativeSystem,
+    MonitorGroup::SecondaryOxygenSensorBank1, MonitorGroup::SecondaryOxygenSensorBank2,
+    MonitorGroup::AirFuelRatioImbalanceBank1, MonitorGroup::AirFuelRatioImbalanceBank2,
+    MonitorGroup::ParticulateFilterBank1, MonitorGroup::ParticulateFilterBank2
+];
+
+const COMPRESSION_GROUPS : [MonitorGroup; 7] = [
+    MonitorGroup::NmhcCatalyst, MonitorGroup::NoxCatalyst, MonitorGroup::NoxAdsorber, MonitorGroup::ParticulateFilter,
+    MonitorGroup::ExhaustGasSensor, MonitorGroup::EgrVvt, MonitorGroup::BoostPressure
+];
+
+// Enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InfoType {
+    //
+    // Informations véhicule du mode 0x09
+    // Les types *MessageCount ne concernent que les protocoles non CAN, où chaque information est répartie
+    // sur plusieurs messages de 4 octets numérotés
+    //
+    SupportedInfoTypes,
+    VinMessageCount,
+    Vin,
+    CalibrationIdMessageCount,
+    CalibrationId,
+    CvnMessageCount,
+    Cvn,
+    PerformanceTrackingMessageCount,
+    PerformanceTrackingSpark,
+    EcuNameMessageCount,
+    EcuName,
+    PerformanceTrackingCompression,
+    EngineSerialNumber,
+    TypeApprovalNumber
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MonitorGroup {
+    //
+    // Groupes de moniteurs suivis par l'IUMPR, dans l'ordre des compteurs des types 0x08 (allumage commandé)
+    // puis 0x0b (allumage par compression)
+    //
+    CatalystBank1,
+    CatalystBank2,
+    OxygenSensorBank1,
+    OxygenSensorBank2,
+    EgrVvt,
+    SecondaryAir,
+    EvaporativeSystem,
+    SecondaryOxygenSensorBank1,
+    SecondaryOxygenSensorBank2,
+    AirFuelRatioImbalanceBank1,
+    AirFuelRatioImbalanceBank2,
+    ParticulateFilterBank1,
+    ParticulateFilterBank2,
+    NmhcCatalyst,
+    NoxCatalyst,
+    NoxAdsorber,
+    ParticulateFilter,
+    ExhaustGasSensor,
+    BoostPressure
+}
+
+// Structs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerformanceRatio {
+    //
+    // Compteurs d'un groupe de moniteurs
+    // :field numerator:   Nombre de fois où les conditions de détection d'un défaut ont été réunies
+    // :field denominator: Nombre de cycles de conduite répondant aux critères du groupe
+    //
+    pub group:       MonitorGroup,
+    pub numerator:   u16,
+    pub denominator: u16
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerformanceTracking {
+    //
+    // Suivi des performances en utilisation (IUMPR), types 0x08 et 0x0b
+    // :field obd_conditions:  Nombre de fois où les conditions générales de surveillance ont été rencontrées
+    // :field ignition_cycles: Nombre de cycles d'allumage
+    // :field ratios:          Compteurs des groupes annoncés, dans l'ordre de la norme
+    //
+    pub ignition:        IgnitionType,
+    pub obd_conditions:  u16,
+    pub ignition_cycles: u16,
+    pub ratios:          Vec<PerformanceRatio>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcuName {
+    //
+    // Nom du calculateur (type 0x0a) : acronyme sur 4 caractères, '-', puis nom complet sur 15 caractères
+    //
+    pub acronym: String,
+    pub name:    String
+}
+
+impl InfoType {
+    pub fn number(&self) -> u8 {
+        match self {
+            InfoType::SupportedInfoTypes              => 0x00,
+            InfoType::VinMessageCount                 => 0x01,
+            InfoType::Vin                             => 0x02,
+            InfoType::CalibrationIdMessageCount       => 0x03,
+            InfoType::CalibrationId                   => 0x04,
+            InfoType::CvnMessageCount                 => 0x05,
+            InfoType::Cvn                             => 0x06,
+            InfoType::PerformanceTrackingMessageCount => 0x07,
+            InfoType::PerformanceTrackingSpark        => 0x08,
+            InfoType::EcuNameMessageCount             => 0x09,
+            InfoType::EcuName                         => 0x0a,
+            InfoType::PerformanceTrackingCompression  => 0x0b,
+            InfoType::EngineSerialNumber              => 0x0d,
+            InfoType::TypeApprovalNumber              => 0x0f
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            InfoType::SupportedInfoTypes              => "Types d'informations supportés [01 - 20]",
+            InfoType::VinMessageCount                 => "Nombre de messages du VIN",
+            InfoType::Vin                             => "Numéro d'identification du véhicule (VIN)",
+            InfoType::CalibrationIdMessageCount       => "Nombre de messages des identifiants de calibration",
+            InfoType::CalibrationId                   => "Identifiants de calibration (CAL ID)",
+            InfoType::CvnMessageCount                 => "Nombre de messages des CVN",
+            InfoType::Cvn                             => "Numéros de vérification de calibration (CVN)",
+            InfoType::PerformanceTrackingMessageCount => "Nombre de messages du suivi des performances",
+            InfoType::PerformanceTrackingSpark        => "Suivi des performances en utilisation, allumage commandé",
+            InfoType::EcuNameMessageCount             => "Nombre de messages du nom du calculateur",
+            InfoType::EcuName                         => "Nom du calculateur",
+            InfoType::PerformanceTrackingCompression  => "Suivi des performances en utilisation, allumage par compression",
+            InfoType::EngineSerialNumber              => "Numéro de série du moteur",
+            InfoType::TypeApprovalNumber              => "Numéro de réception par type (EROTAN)"
+        }
+    }
+
+    pub fn message_count(&self) -> Option<InfoType> {
+        //
+        // Type donnant, hors CAN, le nombre de messages de cette information
+        //
+        match self {
+            InfoType::Vin                            => Some(InfoType::VinMessageCount),
+            InfoType::CalibrationId                  => Some(InfoType::CalibrationIdMessageCount),
+            InfoType::Cvn                            => Some(InfoType::CvnMessageCount),
+            InfoType::PerformanceTrackingSpark       => Some(InfoType::PerformanceTrackingMessageCount),
+            InfoType::PerformanceTrackingCompression => Some(InfoType::PerformanceTrackingMessageCount),
+            InfoType::EcuName                        => Some(InfoType::EcuNameMessageCount),
+            _                                        => None
+        }
+    }
+
+    fn is_single_message(&self) -> bool {
+        //
+        // Réponses tenant dans un seul message, sans nombre d'éléments (CAN) ni numéro de message (autres protocoles)
+        //
+        matches!(self, InfoType::SupportedInfoTypes | InfoType::VinMessageCount | InfoType::CalibrationIdMessageCount
+            | InfoType::CvnMessageCount | InfoType::PerformanceTrackingMessageCount | InfoType::EcuNameMessageCount)
+    }
+}
+
+impl MonitorGroup {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MonitorGroup::CatalystBank1              => "catalyst_bank1",
+            MonitorGroup::CatalystBank2              => "catalyst_bank2",
+            MonitorGroup::OxygenSensorBank1          => "oxygen_sensor_bank1",
+            MonitorGroup::OxygenSensorBank2          => "oxygen_sensor_bank2",
+            MonitorGroup::EgrVvt                     => "egr_vvt",
+            MonitorGroup::SecondaryAir               => "secondary_air",
+            MonitorGroup::EvaporativeSystem          => "evaporative_system",
+            MonitorGroup::SecondaryOxygenSensorBank1 => "secondary_oxygen_sensor_bank1",
+            MonitorGroup::SecondaryOxygenSensorBank2 => "secondary_oxygen_sensor_bank2",
+            MonitorGroup::AirFuelRatioImbalanceBank1 => "air_fuel_ratio_imbalance_bank1",
+            MonitorGroup::AirFuelRatioImbalanceBank2 => "air_fuel_ratio_imbalance_bank2",
+            MonitorGroup::ParticulateFilterBank1     => "particulate_filter_bank1",
+            MonitorGroup::ParticulateFilterBank2     => "particulate_filter_bank2",
+            MonitorGroup::NmhcCatalyst               => "nmhc_catalyst",
+            MonitorGroup::NoxCatalyst                => "nox_catalyst",
+            MonitorGroup::NoxAdsorber                => "nox_adsorber",
+            MonitorGroup::ParticulateFilter          => "particulate_filter",
+            MonitorGroup::ExhaustGasSensor           => "exhaust_gas_sensor",
+            MonitorGroup::BoostPressure              => "boost_pressure"
+        }
+    }
+}
+
+impl PerformanceRatio {
+    pub fn ratio(&self) -> Option<f64> {
+        //
+        // Rapport numérateur / dénominateur, non défini tant que le dénominateur est nul
+        //
+        if self.denominator == 0 { return None; }
+        Some(self.numerator as f64 / self.denominator as f64)
+    }
+}
+
+impl PerformanceTracking {
+    pub fn ratio(&self, group: MonitorGroup) -> Option<&PerformanceRatio> {
+        self.ratios.iter().find(|r| r.group == group)
+    }
+
+    pub fn minimum_ratio(&self) -> Option<&PerformanceRatio> {
+        //
+        // Groupe ayant le plus petit rapport défini, celui retenu lors d'un contrôle
+        //
+        self.ratios.iter()
+            .filter(|r| r.ratio().is_some())
+            .min_by(|a, b| a.ratio().partial_cmp(&b.ratio()).unwrap_or(std::cmp::Ordering::Equal))
+    }
+}
+
+impl fmt::Display for EcuName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.acronym, self.name)
+    }
+}
+
+// Fonctions
+pub fn performance_tracking_info(ignition: IgnitionType) -> InfoType {
+    match ignition {
+        IgnitionType::Spark       => InfoType::PerformanceTrackingSpark,
+        IgnitionType::Compression => InfoType::PerformanceTrackingCompression
+    }
+}
+
+pub fn decode_info_payload(info: InfoType, messages: &[Vec<u8>], can: bool) -> Result<Vec<u8>, PidError> {
+    //
+    // Rassemble les données d'une information du mode 0x09 émise par un calculateur
+    // :param messages: Messages reçus du calculateur, 0x49 et type compris
+    // :param can:      Sur CAN un seul message (réassemblé) débute par le nombre d'éléments,
+    //                  les autres protocoles envoient des messages de 4 octets précédés de leur numéro
+    //
+    let header : [u8; 2] = [0x49, info.number()];
+    if messages.is_empty() { return Err(PidError::Empty); }
+
+    if info.is_single_message() || can {
+        let data : &[u8] = check_response(&header, &messages[0])?;
+        if info.is_single_message() { return Ok(data.to_vec()); }
+        if data.is_empty() { return Err(PidError::TooShort { expected: 3, received: messages[0].len() }); }
+        return Ok(data[1..].to_vec());
+    }
+
+    let mut numbered : Vec<(u8, &[u8])> = vec![];
+    for message in messages.iter() {
+        let data : &[u8] = check_response(&header, message)?;
+        let (number, data) = data.split_first().ok_or(PidError::TooShort { expected: 3, received: message.len() })?;
+        numbered.push((*number, data));
+    }
+    numbered.sort_by_key(|(number, _)| *number);
+    for (expected, (number, _)) in (1..).zip(numbered.iter()) {
+        if *number != expected { return Err(PidError::PidMismatch { expected, received: *number }); }
+    }
+    Ok(numbered.into_iter().flat_map(|(_, data)| data.iter().copied()).collect())
+}
+
+pub fn decode_ascii(data: &[u8]) -> String {
+    //
+    // Convertit un texte ASCII complété par des 0x00, en début (VIN hors CAN) comme en fin
+    //
+    data.iter()
+        .filter(|b| **b != 0x00)
+        .map(|b| if b.is_ascii_graphic() || *b == b' ' { *b as char } else { '?' })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+pub fn decode_vin(data: &[u8]) -> Result<String, PidError> {
+    //
+    // VIN de 17 caractères exactement, une fois retiré le remplissage
+    //
+    let vin : String = decode_ascii(data);
+    ResultSize::Value(17).check(vin.len())?;
+    Ok(vin)
+}
+
+pub fn decode_calibration_ids(data: &[u8]) -> Vec<String> {
+    data.chunks(16).map(decode_ascii).filter(|id| !id.is_empty()).collect()
+}
+
+pub fn decode_cvns(data: &[u8]) -> Vec<u32> {
+    data.chunks_exact(4).map(|cvn| u32::from_be_bytes([cvn[0], cvn[1], cvn[2], cvn[3]])).collect()
+}
+
+pub fn decode_counters(data: &[u8]) -> Vec<u16> {
+    data.chunks_exact(2).map(|counter| u16::from_be_bytes([counter[0], counter[1]])).collect()
+}
+
+pub fn decode_ecu_name(data: &[u8]) -> EcuName {
+    let text : String = data.iter()
+        .map(|b| if b.is_ascii_graphic() || *b == b' ' { *b as char } else { '\0' })
+        .collect();
+    let (acronym, name) = text.split_once('-').unwrap_or((&text, ""));
+    EcuName {
+        acronym: acronym.trim_matches(|c: char| c == '\0' || c == ' ').to_string(),
+        name:    name.trim_matches(|c: char| c == '\0' || c == ' ').to_string()
+    }
+}
+
+pub fn decode_performance_tracking(ignition: IgnitionType, data: &[u8]) -> Result<PerformanceTracking, PidError> {
+    //
+    // Décode les compteurs IUMPR : conditions OBD, cycles d'allumage, puis un couple numérateur / dénominateur par groupe
+    // Les groupes au-delà de ceux connus sont ignorés, les véhicules anciens en annonçant moins
+    //
+    let counters : Vec<u16> = decode_counters(data);
+    if counters.len() < 2 { return Err(PidError::TooShort { expected: 4, received: data.len() }); }
+    let groups : &[MonitorGroup] = match ignition {
+        IgnitionType::Spark       => &SPARK_GROUPS,
+        IgnitionType::Compression => &COMPRESSION_GROUPS
+    };
+    let ratios : Vec<PerformanceRatio> = groups.iter()
+        .zip(counters[2..].chunks_exact(2))
+        .map(|(group, pair)| PerformanceRatio { group: *group, numerator: pair[0], denominator: pair[1] })
+        .collect();
+    Ok(PerformanceTracking { ignition, obd_conditions: counters[0], ignition_cycles: counters[1], ratios })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vin_has_exactly_seventeen_characters() {
+        assert_eq!(decode_vin(b"\0\0\x001G1JC5444R7252367"), Ok("1G1JC5444R7252367".to_string()));
+        assert_eq!(decode_vin(b"1G1JC5444R7252367\0\0\0"), Ok("1G1JC5444R7252367".to_string()));
+        assert_eq!(decode_vin(b"1G1JC5444R725236"), Err(PidError::TooShort { expected: 17, received: 16 }));
+        assert_eq!(decode_vin(b"1G1JC5444R72523678"), Err(PidError::TooLong { expected: 17, received: 18 }));
+    }
+
+    fn counters(values: &[u16]) -> Vec<u8> {
+        values.iter().flat_map(|value| value.to_be_bytes().to_vec()).collect()
+    }
+
+    #[test]
+    fn spark_tracking_counters_follow_0908_order() {
+        // Réponse CAN 49 08 10 : 16 compteurs, soit les conditions OBD, les cycles d'allumage et 7 groupes
+        let mut message : Vec<u8> = vec![0x49, 0x08, 0x10];
+        message.extend(counters(&[16, 32, 5, 10, 0, 0, 3, 4, 1, 8, 7, 7, 0, 0, 2, 20]));
+        let data : Vec<u8> = decode_info_payload(performance_tracking_info(IgnitionType::Spark), &[message], true).unwrap();
+        let tracking : PerformanceTracking = decode_performance_tracking(IgnitionType::Spark, &data).unwrap();
+
+        assert_eq!((tracking.obd_conditions, tracking.ignition_cycles), (16, 32));
+        assert_eq!(tracking.ratios.iter().map(|r| r.group).collect::<Vec<MonitorGroup>>(), SPARK_GROUPS[..7].to_vec());
+        assert_eq!(tracking.ratio(MonitorGroup::CatalystBank1).and_then(|r| r.ratio()), Some(0.5));
+        assert_eq!(tracking.ratio(MonitorGroup::CatalystBank2).and_then(|r| r.ratio()), None);
+        assert_eq!(tracking.ratio(MonitorGroup::OxygenSensorBank2).and_then(|r| r.ratio()), Some(0.125));
+        assert_eq!(tracking.ratio(MonitorGroup::ParticulateFilterBank1), None);
+        assert_eq!(tracking.minimum_ratio().map(|r| r.group), Some(MonitorGroup::EvaporativeSystem));
+    }
+
+    #[test]
+    fn compression_tracking_counters_follow_090b_order() {
+        // Les compteurs au-delà des 7 groupes connus sont ignorés
+        let data : Vec<u8> = counters(&[256, 512, 1, 2, 3, 3, 0, 0, 4, 16, 2, 2, 9, 10, 1, 1, 7, 7]);
+        let tracking : PerformanceTracking = decode_performance_tracking(IgnitionType::Compression, &data).unwrap();
+
+        assert_eq!((tracking.obd_conditions, tracking.ignition_cycles), (256, 512));
+        assert_eq!(tracking.ratios.iter().map(|r| r.group).collect::<Vec<MonitorGroup>>(), COMPRESSION_GROUPS.to_vec());
+        assert_eq!(tracking.ratio(MonitorGroup::NoxAdsorber), Some(&PerformanceRatio { group: MonitorGroup::NoxAdsorber, numerator: 0, denominator: 0 }));
+        assert_eq!(tracking.ratio(MonitorGroup::EgrVvt).and_then(|r| r.ratio()), Some(0.9));
+        assert_eq!(tracking.ratio(MonitorGroup::CatalystBank1), None);
+        assert_eq!(tracking.minimum_ratio().map(|r| r.group), Some(MonitorGroup::ParticulateFilter));
+    }
+
+    #[test]
+    fn tracking_without_defined_ratio() {
+        let tracking : PerformanceTracking = decode_performance_tracking(IgnitionType::Spark, &counters(&[1, 2, 3, 0])).unwrap();
+        assert_eq!(tracking.ratios.len(), 1);
+        assert_eq!(tracking.minimum_ratio(), None);
+        assert_eq!(decode_performance_tracking(IgnitionType::Spark, &[0x00, 0x01, 0x00]), Err(PidError::TooShort { expected: 4, received: 3 }));
+    }
+}
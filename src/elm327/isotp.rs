@@ -0,0 +1,258 @@
+// Uses
+use std::collections::BTreeMap;
+use std::fmt;
+use crate::elm327::response::EcuResponse;
+use crate::elm327::types::*;
+
+// Constantes
+pub const SINGLE_FRAME      : u8 = 0x0;
+pub const FIRST_FRAME       : u8 = 0x1;
+pub const CONSECUTIVE_FRAME : u8 = 0x2;
+pub const FLOW_CONTROL      : u8 = 0x3;
+
+// Enums
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IsoTpError {
+    //
+    // Erreurs de réassemblage d'une réponse multi-trames (ISO 15765-2)
+    //
+    InvalidFrame { ecu: EcuAddress, frame: Vec<u8> },
+    UnexpectedFrame { ecu: EcuAddress, sequence: u8 },
+    MissingFrame { ecu: EcuAddress, expected: u8, received: u8 },
+    Incomplete { ecu: EcuAddress, expected: usize, received: usize }
+}
+
+impl fmt::Display for IsoTpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IsoTpError::InvalidFrame { ecu, frame }             => write!(f, "Trame invalide du calculateur {} : {:02X?}", ecu, frame),
+            IsoTpError::UnexpectedFrame { ecu, sequence }       => write!(f, "Trame consécutive {} du calculateur {} sans première trame", sequence, ecu),
+            IsoTpError::MissingFrame { ecu, expected, received } => write!(f, "Trame manquante du calculateur {} : {} reçue, {} attendue", ecu, received, expected),
+            IsoTpError::Incomplete { ecu, expected, received }  => write!(f, "Message incomplet du calculateur {} : {} octets reçus sur {}", ecu, received, expected)
+        }
+    }
+}
+
+impl std::error::Error for IsoTpError {}
+
+impl IsoTpError {
+    pub fn ecu(&self) -> EcuAddress {
+        match self {
+            IsoTpError::InvalidFrame { ecu, .. }    => *ecu,
+            IsoTpError::UnexpectedFrame { ecu, .. } => *ecu,
+            IsoTpError::MissingFrame { ecu, .. }    => *ecu,
+            IsoTpError::Incomplete { ecu, .. }      => *ecu
+        }
+    }
+}
+
+// Structs
+struct PendingMessage {
+    //
+    // Message multi-trames en cours de réception
+    // :field length: Longueur annoncée par la première trame
+    // :field next:   Numéro de séquence attendu (0 pour les données de la première trame, puis 1 à 15, 0, 1, ...)
+    //
+    length: usize,
+    data:   Vec<u8>,
+    next:   u8
+}
+
+pub struct Reassembler {
+    //
+    // Réassemble les réponses multi-trames, éventuellement entrelacées entre plusieurs calculateurs
+    // Deux présentations de l'elm327 sont prises en charge :
+    // - formatée (CAF1, entêtes masqués) : ligne de longueur "014" puis segments "0:", "1:", ... via first / consecutive
+    // - brute (entêtes affichés) : octet PCI en tête de chaque trame via push_frame
+    // Une erreur n'abandonne que le message en cours du calculateur fautif, elle est retournée puis conservée pour finish
+    //
+    pending:  BTreeMap<EcuAddress, PendingMessage>,
+    complete: Vec<EcuResponse>,
+    errors:   Vec<IsoTpError>
+}
+
+impl Reassembler {
+    pub fn new() -> Self {
+        Reassembler { pending: BTreeMap::new(), complete: vec![], errors: vec![] }
+    }
+
+    fn reject(&mut self, error: IsoTpError) -> IsoTpError {
+        self.pending.remove(&error.ecu());
+        self.errors.push(error.clone());
+        error
+    }
+
+    pub fn single(&mut self, ecu: EcuAddress, data: Vec<u8>) {
+        self.complete.push(EcuResponse { ecu, data });
+    }
+
+    pub fn first(&mut self, ecu: EcuAddress, length: usize) -> Result<(), IsoTpError> {
+        //
+        // Début d'un message de length octets, dont les données suivent avec le numéro de séquence 0
+        // Un message encore en cours pour ce calculateur est abandonné au profit du nouveau
+        //
+        let interrupted : Option<IsoTpError> = self.pending.get(&ecu)
+            .map(|pending| IsoTpError::Incomplete { ecu, expected: pending.length, received: pending.data.len() })
+            .map(|error| self.reject(error));
+        self.pending.insert(ecu, PendingMessage { length, data: Vec::with_capacity(length), next: 0 });
+        interrupted.map_or(Ok(()), Err)
+    }
+
+    pub fn consecutive(&mut self, ecu: EcuAddress, sequence: u8, bytes: &[u8]) -> Result<(), IsoTpError> {
+        //
+        // Ajoute un segment au message en cours, le numéro de séquence repassant à 0 après 15
+        //
+        let pending : &mut PendingMessage = match self.pending.get_mut(&ecu) {
+            Some(pending) => pending,
+            None          => return Err(self.reject(IsoTpError::UnexpectedFrame { ecu, sequence }))
+        };
+        if sequence != pending.next {
+            let expected : u8 = pending.next;
+            return Err(self.reject(IsoTpError::MissingFrame { ecu, expected, received: sequence }));
+        }
+        pending.data.extend_from_slice(bytes);
+        pending.next = (pending.next + 1) & 0x0f;
+        if pending.data.len() >= pending.length {
+            if let Some(mut message) = self.pending.remove(&ecu) {
+                message.data.truncate(message.length);
+                self.complete.push(EcuResponse { ecu, data: message.data });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn push_frame(&mut self, ecu: EcuAddress, frame: &[u8]) -> Result<(), IsoTpError> {
+        //
+        // Ajoute une trame brute, débutant par son octet PCI (type sur 4 bits puis longueur ou séquence)
+        //
+        let invalid : IsoTpError = IsoTpError::InvalidFrame { ecu, frame: frame.to_vec() };
+        let pci : u8 = match frame.first() {
+            Some(pci) => *pci,
+            None      => return Err(self.reject(invalid))
+        };
+        match pci >> 4 {
+            SINGLE_FRAME => {
+                let length : usize = (pci & 0x0f) as usize;
+                if length == 0 || frame.len() < 1 + length { return Err(self.reject(invalid)); }
+                self.single(ecu, frame[1..1 + length].to_vec());
+                Ok(())
+            },
+            FIRST_FRAME => {
+                if frame.len() < 2 { return Err(self.reject(invalid)); }
+                let length : usize = ((pci & 0x0f) as usize) << 8 | frame[1] as usize;
+                let interrupted : Result<(), IsoTpError> = self.first(ecu, length);
+                self.consecutive(ecu, 0, &frame[2..])?;
+                interrupted
+            },
+            CONSECUTIVE_FRAME => self.consecutive(ecu, pci & 0x0f, &frame[1..]),
+            FLOW_CONTROL      => Ok(()),
+            _                 => Err(self.reject(invalid))
+        }
+    }
+
+    pub fn is_pending(&self, ecu: EcuAddress) -> bool {
+        self.pending.contains_key(&ecu)
+    }
+
+    pub fn finish(self) -> (Vec<EcuResponse>, Vec<IsoTpError>) {
+        //
+        // Retourne les messages complets dans leur ordre d'achèvement, et les erreurs rencontrées dans l'ordre,
+        // chaque message encore inachevé s'y ajoutant ; les messages complets des autres calculateurs sont conservés
+        //
+        let mut errors : Vec<IsoTpError> = self.errors;
+        errors.extend(self.pending.iter().map(|(ecu, pending)| IsoTpError::Incomplete { ecu: *ecu, expected: pending.length, received: pending.data.len() }));
+        (self.complete, errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn consecutive_frames(ecu: EcuAddress, payload: &[u8], reassembler: &mut Reassembler) {
+        //
+        // Découpe payload en première trame (6 octets) puis trames consécutives (7 octets) au format brut
+        //
+        let mut first : Vec<u8> = vec![0x10 | (payload.len() >> 8) as u8, payload.len() as u8];
+        first.extend_from_slice(&payload[..6]);
+        reassembler.push_frame(ecu, &first).unwrap();
+        for (index, chunk) in payload[6..].chunks(7).enumerate() {
+            let mut frame : Vec<u8> = vec![0x20 | ((index + 1) & 0x0f) as u8];
+            frame.extend_from_slice(chunk);
+            reassembler.push_frame(ecu, &frame).unwrap();
+        }
+    }
+
+    #[test]
+    fn interleaved_ecus_are_reassembled_separately() {
+        let mut reassembler : Reassembler = Reassembler::new();
+        reassembler.push_frame(0x7e8, &[0x10, 0x0b, 0x49, 0x04, 0x01, 0x43, 0x41, 0x4c]).unwrap();
+        reassembler.push_frame(0x7e9, &[0x10, 0x09, 0x49, 0x04, 0x01, 0x54, 0x43, 0x4d]).unwrap();
+        reassembler.push_frame(0x7ea, &[0x06, 0x41, 0x00, 0xbe, 0x1f, 0xa8, 0x13, 0x55]).unwrap();
+        reassembler.push_frame(0x7e9, &[0x21, 0x31, 0x32, 0x33, 0x00, 0x00, 0x00, 0x00]).unwrap();
+        assert!(reassembler.is_pending(0x7e8) && !reassembler.is_pending(0x7e9));
+        reassembler.push_frame(0x7e8, &[0x21, 0x49, 0x44, 0x30, 0x31, 0x00, 0x00, 0x00]).unwrap();
+
+        let (complete, errors) = reassembler.finish();
+        assert!(errors.is_empty());
+        assert_eq!(complete, vec![
+            EcuResponse { ecu: 0x7ea, data: vec![0x41, 0x00, 0xbe, 0x1f, 0xa8, 0x13] },
+            EcuResponse { ecu: 0x7e9, data: vec![0x49, 0x04, 0x01, 0x54, 0x43, 0x4d, 0x31, 0x32, 0x33] },
+            EcuResponse { ecu: 0x7e8, data: vec![0x49, 0x04, 0x01, 0x43, 0x41, 0x4c, 0x49, 0x44, 0x30, 0x31, 0x00] }
+        ]);
+    }
+
+    #[test]
+    fn sequence_wraps_after_fifteen() {
+        //
+        // 6 + 17 * 7 = 125 octets : trames consécutives 1 à F puis 0 et 1
+        //
+        let payload : Vec<u8> = (0..125).collect();
+        let mut reassembler : Reassembler = Reassembler::new();
+        consecutive_frames(0x7e8, &payload, &mut reassembler);
+        let (complete, errors) = reassembler.finish();
+        assert!(errors.is_empty());
+        assert_eq!(complete, vec![EcuResponse { ecu: 0x7e8, data: payload }]);
+    }
+
+    #[test]
+    fn missing_frame_keeps_other_ecus() {
+        let mut reassembler : Reassembler = Reassembler::new();
+        reassembler.push_frame(0x7e8, &[0x10, 0x14, 0x49, 0x02, 0x01, 0x31, 0x44, 0x34]).unwrap();
+        reassembler.push_frame(0x7e9, &[0x10, 0x08, 0x49, 0x0a, 0x01, 0x45, 0x43, 0x4d]).unwrap();
+        assert_eq!(reassembler.push_frame(0x7e8, &[0x22, 0x42, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36]),
+            Err(IsoTpError::MissingFrame { ecu: 0x7e8, expected: 1, received: 2 }));
+        reassembler.push_frame(0x7e9, &[0x21, 0x00, 0x45, 0x6e, 0x67, 0x69, 0x6e, 0x65]).unwrap();
+        assert!(!reassembler.is_pending(0x7e8));
+
+        let (complete, errors) = reassembler.finish();
+        assert_eq!(errors, vec![IsoTpError::MissingFrame { ecu: 0x7e8, expected: 1, received: 2 }]);
+        assert_eq!(complete, vec![EcuResponse { ecu: 0x7e9, data: vec![0x49, 0x0a, 0x01, 0x45, 0x43, 0x4d, 0x00, 0x45] }]);
+    }
+
+    #[test]
+    fn first_frame_interrupts_pending_message() {
+        let mut reassembler : Reassembler = Reassembler::new();
+        reassembler.push_frame(0x7e8, &[0x10, 0x14, 0x49, 0x02, 0x01, 0x31, 0x44, 0x34]).unwrap();
+        assert_eq!(reassembler.push_frame(0x7e8, &[0x10, 0x08, 0x49, 0x04, 0x01, 0x41, 0x42, 0x43]),
+            Err(IsoTpError::Incomplete { ecu: 0x7e8, expected: 0x14, received: 6 }));
+        reassembler.push_frame(0x7e8, &[0x21, 0x44, 0x45, 0x00, 0x00, 0x00, 0x00, 0x00]).unwrap();
+
+        let (complete, errors) = reassembler.finish();
+        assert_eq!(errors, vec![IsoTpError::Incomplete { ecu: 0x7e8, expected: 0x14, received: 6 }]);
+        assert_eq!(complete, vec![EcuResponse { ecu: 0x7e8, data: vec![0x49, 0x04, 0x01, 0x41, 0x42, 0x43, 0x44, 0x45] }]);
+    }
+
+    #[test]
+    fn unfinished_and_orphan_frames_are_reported() {
+        let mut reassembler : Reassembler = Reassembler::new();
+        assert_eq!(reassembler.push_frame(0x7e9, &[0x21, 0x00]), Err(IsoTpError::UnexpectedFrame { ecu: 0x7e9, sequence: 1 }));
+        assert!(matches!(reassembler.push_frame(0x7ea, &[0x05, 0x41]), Err(IsoTpError::InvalidFrame { ecu: 0x7ea, .. })));
+        reassembler.push_frame(0x7e8, &[0x10, 0x14, 0x49, 0x02, 0x01, 0x31, 0x44, 0x34]).unwrap();
+
+        let (complete, errors) = reassembler.finish();
+        assert!(complete.is_empty());
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[2], IsoTpError::Incomplete { ecu: 0x7e8, expected: 0x14, received: 6 });
+    }
+}
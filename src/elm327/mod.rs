@@ -2,4 +2,18 @@
 pub mod definition;
 pub mod pids;
 pub mod decoder;
-pub mod types;
\ No newline at end of file
+pub mod types;
+pub mod transport;
+pub mod tcp;
+pub mod session;
+pub mod response;
+pub mod registry;
+pub mod supported;
+pub mod dtc;
+pub mod info;
+pub mod monitoring;
+pub mod isotp;
+pub mod frame;
+pub mod protocol;
+#[cfg(target_os = "linux")]
+pub mod serial;
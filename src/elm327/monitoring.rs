@@ -0,0 +1,586 @@
+// Uses
+use std::fmt;
+use crate::elm327::decoder::{OxygenSensorLayout, OxygenSensorLocation};
+use crate::elm327::pids::{check_response, PidError};
+
+// Constantes
+const RECORD_LEN : usize = 9;
+
+// Enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OxygenSensorTest {
+    //
+    // Tests des capteurs d'oxygène du mode 0x05 (protocoles non CAN)
+    // Les tests 0x01 à 0x04 sont des constantes, 0x05 à 0x0a des valeurs calculées accompagnées de leurs limites
+    //
+    RichToLeanThreshold,
+    LeanToRichThreshold,
+    LowSwitchVoltage,
+    HighSwitchVoltage,
+    RichToLeanSwitchTime,
+    LeanToRichSwitchTime,
+    MinimumVoltage,
+    MaximumVoltage,
+    TransitionTime,
+    Period,
+    Manufacturer(u8)
+}
+
+// Structs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitScaling {
+    //
+    // Unité et mise à l'échelle d'un résultat de test (UASID, SAE J1979 annexe E)
+    // :field id:          Identifiant, le bit 7 indiquant une valeur signée
+    // :field scale:       Valeur d'un bit
+    // :field offset:      Décalage ajouté après mise à l'échelle
+    // :field unit:        Unité du résultat
+    // :field description: Grandeur mesurée
+    //
+    pub id:          u8,
+    pub scale:       f64,
+    pub offset:      f64,
+    pub unit:        Option<&'static str>,
+    pub description: &'static str
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorTestResult {
+    //
+    // Résultat d'un test de surveillance embarquée (mode 0x06 sur CAN)
+    // :field obdmid:  Moniteur testé
+    // :field tid:     Identifiant du test, défini par le constructeur au-delà de 0x80
+    // :field uasid:   Identifiant d'unité et de mise à l'échelle des trois valeurs
+    // :field raw_*:   Valeurs brutes transmises
+    //
+    pub obdmid:    u8,
+    pub tid:       u8,
+    pub uasid:     u8,
+    pub raw_value: u16,
+    pub raw_min:   u16,
+    pub raw_max:   u16
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OxygenSensorTestResult {
+    //
+    // Résultat d'un test de capteur d'oxygène (mode 0x05)
+    // :field sensor:    Numéro du capteur, de 1 à 8 comme OxygenSensor1 à OxygenSensor8
+    // :field raw_*:     Valeurs brutes transmises, les limites étant absentes pour les constantes
+    //
+    pub test:      OxygenSensorTest,
+    pub sensor:    u8,
+    pub raw_value: u8,
+    pub raw_min:   Option<u8>,
+    pub raw_max:   Option<u8>
+}
+
+impl OxygenSensorTest {
+    pub fn from_tid(tid: u8) -> Self {
+        match tid {
+            0x01 => OxygenSensorTest::RichToLeanThreshold,
+            0x02 => OxygenSensorTest::LeanToRichThreshold,
+            0x03 => OxygenSensorTest::LowSwitchVoltage,
+            0x04 => OxygenSensorTest::HighSwitchVoltage,
+            0x05 => OxygenSensorTest::RichToLeanSwitchTime,
+            0x06 => OxygenSensorTest::LeanToRichSwitchTime,
+            0x07 => OxygenSensorTest::MinimumVoltage,
+            0x08 => OxygenSensorTest::MaximumVoltage,
+            0x09 => OxygenSensorTest::TransitionTime,
+            0x0a => OxygenSensorTest::Period,
+            _    => OxygenSensorTest::Manufacturer(tid)
+        }
+    }
+
+    pub fn tid(&self) -> u8 {
+        match self {
+            OxygenSensorTest::RichToLeanThreshold  => 0x01,
+            OxygenSensorTest::LeanToRichThreshold  => 0x02,
+            OxygenSensorTest::LowSwitchVoltage     => 0x03,
+            OxygenSensorTest::HighSwitchVoltage    => 0x04,
+            OxygenSensorTest::RichToLeanSwitchTime => 0x05,
+            OxygenSensorTest::LeanToRichSwitchTime => 0x06,
+            OxygenSensorTest::MinimumVoltage       => 0x07,
+            OxygenSensorTest::MaximumVoltage       => 0x08,
+            OxygenSensorTest::TransitionTime       => 0x09,
+            OxygenSensorTest::Period               => 0x0a,
+            OxygenSensorTest::Manufacturer(tid)    => *tid
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            OxygenSensorTest::RichToLeanThreshold  => "Seuil de tension riche vers pauvre",
+            OxygenSensorTest::LeanToRichThreshold  => "Seuil de tension pauvre vers riche",
+            OxygenSensorTest::LowSwitchVoltage     => "Tension basse pour le calcul du temps de basculement",
+            OxygenSensorTest::HighSwitchVoltage    => "Tension haute pour le calcul du temps de basculement",
+            OxygenSensorTest::RichToLeanSwitchTime => "Temps de basculement riche vers pauvre",
+            OxygenSensorTest::LeanToRichSwitchTime => "Temps de basculement pauvre vers riche",
+            OxygenSensorTest::MinimumVoltage       => "Tension minimale sur le cycle de test",
+            OxygenSensorTest::MaximumVoltage       => "Tension maximale sur le cycle de test",
+            OxygenSensorTest::TransitionTime       => "Temps entre deux transitions",
+            OxygenSensorTest::Period               => "Période du capteur",
+            OxygenSensorTest::Manufacturer(_)      => "Test défini par le constructeur"
+        }
+    }
+
+    pub fn scale(&self) -> f64 {
+        match self {
+            OxygenSensorTest::RichToLeanThreshold | OxygenSensorTest::LeanToRichThreshold
+            | OxygenSensorTest::LowSwitchVoltage | OxygenSensorTest::HighSwitchVoltage
+            | OxygenSensorTest::MinimumVoltage | OxygenSensorTest::MaximumVoltage => 0.005,
+            OxygenSensorTest::RichToLeanSwitchTime | OxygenSensorTest::LeanToRichSwitchTime => 0.004,
+            OxygenSensorTest::TransitionTime | OxygenSensorTest::Period => 0.04,
+            OxygenSensorTest::Manufacturer(_) => 1.0
+        }
+    }
+
+    pub fn unit(&self) -> Option<&'static str> {
+        match self {
+            OxygenSensorTest::RichToLeanThreshold | OxygenSensorTest::LeanToRichThreshold
+            | OxygenSensorTest::LowSwitchVoltage | OxygenSensorTest::HighSwitchVoltage
+            | OxygenSensorTest::MinimumVoltage | OxygenSensorTest::MaximumVoltage => Some("V"),
+            OxygenSensorTest::RichToLeanSwitchTime | OxygenSensorTest::LeanToRichSwitchTime
+            | OxygenSensorTest::TransitionTime | OxygenSensorTest::Period => Some("s"),
+            OxygenSensorTest::Manufacturer(_) => None
+        }
+    }
+}
+
+impl OxygenSensorTestResult {
+    pub fn location(&self, layout: OxygenSensorLayout) -> Option<OxygenSensorLocation> {
+        layout.location(self.sensor)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.raw_value as f64 * self.test.scale()
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.raw_min.map(|raw| raw as f64 * self.test.scale())
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.raw_max.map(|raw| raw as f64 * self.test.scale())
+    }
+
+    pub fn passed(&self) -> Option<bool> {
+        //
+        // Non défini pour les constantes, transmises sans limites
+        //
+        match (self.raw_min, self.raw_max) {
+            (Some(min), Some(max)) => Some(min <= self.raw_value && self.raw_value <= max),
+            _                      => None
+        }
+    }
+}
+
+impl UnitScaling {
+    pub fn signed(&self) -> bool {
+        self.id & 0x80 != 0
+    }
+
+    pub fn convert(&self, raw: u16) -> f64 {
+        let raw : f64 = if self.signed() { raw as i16 as f64 } else { raw as f64 };
+        raw * self.scale + self.offset
+    }
+}
+
+impl MonitorTestResult {
+    pub fn scaling(&self) -> Option<UnitScaling> {
+        unit_scaling(self.uasid)
+    }
+
+    pub fn description(&self) -> &'static str {
+        obdmid_description(self.obdmid)
+    }
+
+    pub fn unit(&self) -> Option<&'static str> {
+        self.scaling().and_then(|s| s.unit)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.convert(self.raw_value)
+    }
+
+    pub fn min(&self) -> f64 {
+        self.convert(self.raw_min)
+    }
+
+    pub fn max(&self) -> f64 {
+        self.convert(self.raw_max)
+    }
+
+    pub fn passed(&self) -> bool {
+        //
+        // Le test est réussi si la valeur est comprise entre les limites, bornes incluses
+        //
+        self.min() <= self.value() && self.value() <= self.max()
+    }
+
+    fn convert(&self, raw: u16) -> f64 {
+        //
+        // Un UASID inconnu laisse la valeur brute, signée si le bit 7 est positionné
+        //
+        match self.scaling() {
+            Some(scaling) => scaling.convert(raw),
+            None if self.uasid & 0x80 != 0 => raw as i16 as f64,
+            None => raw as f64
+        }
+    }
+}
+
+impl fmt::Display for MonitorTestResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unit : &str = self.unit().unwrap_or("");
+        write!(f, "{} (test 0x{:02x}) : {} {} [{} - {}] {}",
+            self.description(), self.tid, self.value(), unit, self.min(), self.max(),
+            if self.passed() { "OK" } else { "ÉCHEC" })
+    }
+}
+
+// Fonctions
+pub fn unit_scaling(uasid: u8) -> Option<UnitScaling> {
+    let (scale, offset, unit, description) : (f64, f64, Option<&'static str>, &'static str) = match uasid {
+        0x01 => (1.0,         0.0,     None,             "Valeur brute"),
+        0x02 => (0.1,         0.0,     None,             "Valeur brute"),
+        0x03 => (0.01,        0.0,     None,             "Valeur brute"),
+        0x04 => (0.001,       0.0,     None,             "Valeur brute"),
+        0x05 => (0.0000305,   0.0,     None,             "Valeur brute"),
+        0x06 => (0.000305,    0.0,     None,             "Valeur brute"),
+        0x07 => (0.25,        0.0,     Some("rpm"),      "Fréquence de rotation"),
+        0x08 => (0.01,        0.0,     Some("km/h"),     "Vitesse"),
+        0x09 => (1.0,         0.0,     Some("km/h"),     "Vitesse"),
+        0x0a => (0.122,       0.0,     Some("mV"),       "Tension"),
+        0x0b => (0.001,       0.0,     Some("V"),        "Tension"),
+        0x0c => (0.01,        0.0,     Some("V"),        "Tension"),
+        0x0d => (0.00390625,  0.0,     Some("mA"),       "Courant"),
+        0x0e => (0.001,       0.0,     Some("A"),        "Courant"),
+        0x0f => (0.01,        0.0,     Some("A"),        "Courant"),
+        0x10 => (1.0,         0.0,     Some("ms"),       "Durée"),
+        0x11 => (100.0,       0.0,     Some("ms"),       "Durée"),
+        0x12 => (1.0,         0.0,     Some("s"),        "Durée"),
+        0x13 => (1.0,         0.0,     Some("mOhm"),     "Résistance"),
+        0x14 => (1.0,         0.0,     Some("Ohm"),      "Résistance"),
+        0x15 => (1.0,         0.0,     Some("kOhm"),     "Résistance"),
+        0x16 => (0.1,         -40.0,   Some("°C"),       "Température"),
+        0x17 => (0.01,        0.0,     Some("kPa"),      "Pression relative"),
+        0x18 => (0.0117,      0.0,     Some("kPa"),      "Pression d'air"),
+        0x19 => (0.079,       0.0,     Some("kPa"),      "Pression de carburant"),
+        0x1a => (1.0,         0.0,     Some("kPa"),      "Pression relative"),
+        0x1b => (10.0,        0.0,     Some("kPa"),      "Pression gazole"),
+        0x1c => (0.01,        0.0,     Some("°"),        "Angle"),
+        0x1d => (0.5,         0.0,     Some("°"),        "Angle"),
+        0x1e => (0.0000305,   0.0,     None,             "Richesse (lambda)"),
+        0x1f => (0.05,        0.0,     None,             "Rapport air / carburant"),
+        0x20 => (0.0039062,   0.0,     None,             "Rapport"),
+        0x21 => (1.0,         0.0,     Some("mHz"),      "Fréquence"),
+        0x22 => (1.0,         0.0,     Some("Hz"),       "Fréquence"),
+        0x23 => (1.0,         0.0,     Some("kHz"),      "Fréquence"),
+        0x24 => (1.0,         0.0,     None,             "Compteur"),
+        0x25 => (1.0,         0.0,     Some("km"),       "Distance"),
+        0x26 => (0.1,         0.0,     Some("mV/ms"),    "Variation de tension"),
+        0x27 => (0.01,        0.0,     Some("g/s"),      "Débit massique"),
+        0x28 => (1.0,         0.0,     Some("g/s"),      "Débit massique"),
+        0x29 => (0.25,        0.0,     Some("Pa/s"),     "Variation de pression"),
+        0x2a => (0.001,       0.0,     Some("kg/h"),     "Débit massique"),
+        0x2b => (1.0,         0.0,     None,             "Commutations"),
+        0x2c => (0.01,        0.0,     Some("g/cyl"),    "Masse par cylindre"),
+        0x2d => (0.01,        0.0,     Some("mg/stroke"),"Masse par course"),
+        0x2e => (1.0,         0.0,     None,             "Vrai / faux"),
+        0x2f => (0.01,        0.0,     Some("%"),        "Pourcentage"),
+        0x30 => (0.001526,    0.0,     Some("%"),        "Pourcentage"),
+        0x31 => (0.001,       0.0,     Some("L"),        "Volume"),
+        0x32 => (0.0007747,   0.0,     Some("mm"),       "Longueur"),
+        0x33 => (0.00024414,  0.0,     None,             "Richesse (lambda)"),
+        0x34 => (1.0,         0.0,     Some("min"),      "Durée"),
+        0x35 => (10.0,        0.0,     Some("ms"),       "Durée"),
+        0x36 => (0.01,        0.0,     Some("g"),        "Masse"),
+        0x37 => (0.1,         0.0,     Some("g"),        "Masse"),
+        0x38 => (1.0,         0.0,     Some("g"),        "Masse"),
+        0x39 => (0.01,        -327.68, Some("%"),        "Pourcentage"),
+        0x3a => (0.001,       0.0,     Some("g"),        "Masse"),
+        0x3b => (0.0001,      0.0,     Some("g"),        "Masse"),
+        0x3c => (0.1,         0.0,     Some("µs"),       "Durée"),
+        0x3d => (0.01,        0.0,     Some("mA"),       "Courant"),
+        0x3e => (0.00006103516, 0.0,   Some("mm²"),      "Surface"),
+        0x81 => (1.0,         0.0,     None,             "Valeur brute signée"),
+        0x82 => (0.1,         0.0,     None,             "Valeur brute signée"),
+        0x83 => (0.01,        0.0,     None,             "Valeur brute signée"),
+        0x84 => (0.001,       0.0,     None,             "Valeur brute signée"),
+        0x85 => (0.0000305,   0.0,     None,             "Valeur brute signée"),
+        0x86 => (0.000305,    0.0,     None,             "Valeur brute signée"),
+        0x8a => (0.122,       0.0,     Some("mV"),       "Tension"),
+        0x8b => (0.001,       0.0,     Some("V"),        "Tension"),
+        0x8c => (0.01,        0.0,     Some("V"),        "Tension"),
+        0x8d => (0.00390625,  0.0,     Some("mA"),       "Courant"),
+        0x8e => (0.001,       0.0,     Some("A"),        "Courant"),
+        0x90 => (1.0,         0.0,     Some("ms"),       "Durée"),
+        0x96 => (0.1,         0.0,     Some("°C"),       "Température"),
+        0x9c => (0.01,        0.0,     Some("°"),        "Angle"),
+        0x9d => (0.5,         0.0,     Some("°"),        "Angle"),
+        0xa8 => (1.0,         0.0,     Some("g/s"),      "Débit massique"),
+        0xa9 => (0.25,        0.0,     Some("Pa/s"),     "Variation de pression"),
+        0xad => (0.01,        0.0,     Some("mg/stroke"),"Masse par course"),
+        0xae => (0.1,         0.0,     Some("mg/stroke"),"Masse par course"),
+        0xaf => (0.01,        0.0,     Some("%"),        "Pourcentage"),
+        0xb0 => (0.003052,    0.0,     Some("%"),        "Pourcentage"),
+        0xb1 => (2.0,         0.0,     Some("mV/s"),     "Variation de tension"),
+        0xfc => (0.01,        0.0,     Some("kPa"),      "Pression"),
+        0xfd => (0.001,       0.0,     Some("kPa"),      "Pression"),
+        0xfe => (0.25,        0.0,     Some("Pa"),       "Pression"),
+        _    => return None
+    };
+    Some(UnitScaling { id: uasid, scale, offset, unit, description })
+}
+
+pub fn obdmid_description(obdmid: u8) -> &'static str {
+    match obdmid {
+        0x00 => "OBDMID supportés [01 - 20]",
+        0x01 => "Moniteur sonde à oxygène B1S1",
+        0x02 => "Moniteur sonde à oxygène B1S2",
+        0x03 => "Moniteur sonde à oxygène B1S3",
+        0x04 => "Moniteur sonde à oxygène B1S4",
+        0x05 => "Moniteur sonde à oxygène B2S1",
+        0x06 => "Moniteur sonde à oxygène B2S2",
+        0x07 => "Moniteur sonde à oxygène B2S3",
+        0x08 => "Moniteur sonde à oxygène B2S4",
+        0x09 => "Moniteur sonde à oxygène B3S1",
+        0x0a => "Moniteur sonde à oxygène B3S2",
+        0x0b => "Moniteur sonde à oxygène B3S3",
+        0x0c => "Moniteur sonde à oxygène B3S4",
+        0x0d => "Moniteur sonde à oxygène B4S1",
+        0x0e => "Moniteur sonde à oxygène B4S2",
+        0x0f => "Moniteur sonde à oxygène B4S3",
+        0x10 => "Moniteur sonde à oxygène B4S4",
+        0x20 => "OBDMID supportés [21 - 40]",
+        0x21 => "Moniteur catalyseur banque 1",
+        0x22 => "Moniteur catalyseur banque 2",
+        0x23 => "Moniteur catalyseur banque 3",
+        0x24 => "Moniteur catalyseur banque 4",
+        0x31 => "Moniteur EGR banque 1",
+        0x32 => "Moniteur EGR banque 2",
+        0x33 => "Moniteur EGR banque 3",
+        0x34 => "Moniteur EGR banque 4",
+        0x35 => "Moniteur VVT banque 1",
+        0x36 => "Moniteur VVT banque 2",
+        0x37 => "Moniteur VVT banque 3",
+        0x38 => "Moniteur VVT banque 4",
+        0x39 => "Moniteur EVAP (bouchon absent / 0.150\")",
+        0x3a => "Moniteur EVAP (0.090\")",
+        0x3b => "Moniteur EVAP (0.040\")",
+        0x3c => "Moniteur EVAP (0.020\")",
+        0x3d => "Moniteur débit de purge",
+        0x40 => "OBDMID supportés [41 - 60]",
+        0x41 => "Moniteur réchauffeur sonde à oxygène B1S1",
+        0x42 => "Moniteur réchauffeur sonde à oxygène B1S2",
+        0x43 => "Moniteur réchauffeur sonde à oxygène B1S3",
+        0x44 => "Moniteur réchauffeur sonde à oxygène B1S4",
+        0x45 => "Moniteur réchauffeur sonde à oxygène B2S1",
+        0x46 => "Moniteur réchauffeur sonde à oxygène B2S2",
+        0x47 => "Moniteur réchauffeur sonde à oxygène B2S3",
+        0x48 => "Moniteur réchauffeur sonde à oxygène B2S4",
+        0x49 => "Moniteur réchauffeur sonde à oxygène B3S1",
+        0x4a => "Moniteur réchauffeur sonde à oxygène B3S2",
+        0x4b => "Moniteur réchauffeur sonde à oxygène B3S3",
+        0x4c => "Moniteur réchauffeur sonde à oxygène B3S4",
+        0x4d => "Moniteur réchauffeur sonde à oxygène B4S1",
+        0x4e => "Moniteur réchauffeur sonde à oxygène B4S2",
+        0x4f => "Moniteur réchauffeur sonde à oxygène B4S3",
+        0x50 => "Moniteur réchauffeur sonde à oxygène B4S4",
+        0x60 => "OBDMID supportés [61 - 80]",
+        0x61 => "Moniteur catalyseur chauffé banque 1",
+        0x62 => "Moniteur catalyseur chauffé banque 2",
+        0x63 => "Moniteur catalyseur chauffé banque 3",
+        0x64 => "Moniteur catalyseur chauffé banque 4",
+        0x71 => "Moniteur air secondaire 1",
+        0x72 => "Moniteur air secondaire 2",
+        0x73 => "Moniteur air secondaire 3",
+        0x74 => "Moniteur air secondaire 4",
+        0x80 => "OBDMID supportés [81 - A0]",
+        0x81 => "Moniteur système carburant banque 1",
+        0x82 => "Moniteur système carburant banque 2",
+        0x83 => "Moniteur système carburant banque 3",
+        0x84 => "Moniteur système carburant banque 4",
+        0x85 => "Moniteur régulation de suralimentation banque 1",
+        0x86 => "Moniteur régulation de suralimentation banque 2",
+        0x90 => "Moniteur piège à NOx banque 1",
+        0x91 => "Moniteur piège à NOx banque 2",
+        0x98 => "Moniteur catalyseur NOx / SCR banque 1",
+        0x99 => "Moniteur catalyseur NOx / SCR banque 2",
+        0xa0 => "OBDMID supportés [A1 - C0]",
+        0xa1 => "Moniteur ratés d'allumage, données générales",
+        0xa2 => "Moniteur ratés d'allumage cylindre 1",
+        0xa3 => "Moniteur ratés d'allumage cylindre 2",
+        0xa4 => "Moniteur ratés d'allumage cylindre 3",
+        0xa5 => "Moniteur ratés d'allumage cylindre 4",
+        0xa6 => "Moniteur ratés d'allumage cylindre 5",
+        0xa7 => "Moniteur ratés d'allumage cylindre 6",
+        0xa8 => "Moniteur ratés d'allumage cylindre 7",
+        0xa9 => "Moniteur ratés d'allumage cylindre 8",
+        0xaa => "Moniteur ratés d'allumage cylindre 9",
+        0xab => "Moniteur ratés d'allumage cylindre 10",
+        0xac => "Moniteur ratés d'allumage cylindre 11",
+        0xad => "Moniteur ratés d'allumage cylindre 12",
+        0xb0 => "Moniteur filtre à particules banque 1",
+        0xb1 => "Moniteur filtre à particules banque 2",
+        0xc0 => "OBDMID supportés [C1 - E0]",
+        0xe0 => "OBDMID supportés [E1 - FF]",
+        _    => "OBDMID inconnu"
+    }
+}
+
+pub fn decode_monitor_tests(response: &[u8]) -> Result<Vec<MonitorTestResult>, PidError> {
+    //
+    // Décode une réponse CAN au mode 0x06 : 0x46 puis 9 octets par test
+    // (OBDMID, TID, UASID, valeur, minimum, maximum sur 2 octets chacun)
+    //
+    let data : &[u8] = check_response(&[0x46], response)?;
+    if data.is_empty() || data.len() % RECORD_LEN != 0 {
+        return Err(PidError::TooShort { expected: 1 + RECORD_LEN * (data.len() / RECORD_LEN + 1), received: response.len() });
+    }
+    Ok(data.chunks_exact(RECORD_LEN)
+        .map(|r| MonitorTestResult {
+            obdmid:    r[0],
+            tid:       r[1],
+            uasid:     r[2],
+            raw_value: u16::from_be_bytes([r[3], r[4]]),
+            raw_min:   u16::from_be_bytes([r[5], r[6]]),
+            raw_max:   u16::from_be_bytes([r[7], r[8]])
+        })
+        .collect())
+}
+
+pub fn decode_oxygen_sensor_test(response: &[u8]) -> Result<OxygenSensorTestResult, PidError> {
+    //
+    // Décode une réponse au mode 0x05 : 0x45, TID, capteur, valeur puis, pour les valeurs calculées, minimum et maximum
+    //
+    let tid    : u8 = response.get(1).copied().unwrap_or(0);
+    let sensor : u8 = response.get(2).copied().unwrap_or(0);
+    let data : &[u8] = check_response(&[0x45, tid, sensor], response)?;
+    let (raw_min, raw_max) : (Option<u8>, Option<u8>) = match data.len() {
+        1 => (None, None),
+        3 => (Some(data[1]), Some(data[2])),
+        0 => return Err(PidError::TooShort { expected: 4, received: response.len() }),
+        _ => return Err(PidError::TooLong  { expected: 6, received: response.len() })
+    };
+    Ok(OxygenSensorTestResult { test: OxygenSensorTest::from_tid(tid), sensor, raw_value: data[0], raw_min, raw_max })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn record(obdmid: u8, tid: u8, uasid: u8, value: u16, min: u16, max: u16) -> Vec<u8> {
+        let mut record : Vec<u8> = vec![obdmid, tid, uasid];
+        for raw in [value, min, max].iter() { record.extend_from_slice(&raw.to_be_bytes()); }
+        record
+    }
+
+    #[test]
+    fn signed_and_offset_scalings() {
+        // UASID 0x81 : valeur brute signée
+        let signed = MonitorTestResult { obdmid: 0x01, tid: 0x01, uasid: 0x81, raw_value: 0xffce, raw_min: 0xff9c, raw_max: 0x0000 };
+        assert_eq!((signed.value(), signed.min(), signed.max()), (-50.0, -100.0, 0.0));
+        assert!(signed.passed());
+
+        // UASID 0x16 : 0,1 °C par bit décalé de -40 °C
+        let offset = MonitorTestResult { obdmid: 0x01, tid: 0x02, uasid: 0x16, raw_value: 0x0384, raw_min: 0x0190, raw_max: 0x0258 };
+        assert!(close(offset.value(), 50.0) && close(offset.min(), 0.0) && close(offset.max(), 20.0));
+        assert_eq!(offset.unit(), Some("°C"));
+        assert!(!offset.passed());
+
+        assert!(close(unit_scaling(0x39).unwrap().convert(0), -327.68));
+        assert!(unit_scaling(0x8a).unwrap().signed() && !unit_scaling(0x0a).unwrap().signed());
+    }
+
+    #[test]
+    fn unknown_scalings_keep_the_raw_value() {
+        let unsigned = MonitorTestResult { obdmid: 0x01, tid: 0x01, uasid: 0x7f, raw_value: 0x8000, raw_min: 0, raw_max: 0xffff };
+        let signed   = MonitorTestResult { uasid: 0xff, ..unsigned.clone() };
+        assert_eq!((unsigned.value(), unsigned.unit()), (32768.0, None));
+        assert_eq!((signed.value(), signed.min(), signed.max()), (-32768.0, 0.0, -1.0));
+        assert!(!signed.passed());
+    }
+
+    #[test]
+    fn limits_are_inclusive() {
+        let at_min = MonitorTestResult { obdmid: 0x01, tid: 0x01, uasid: 0x01, raw_value: 10, raw_min: 10, raw_max: 20 };
+        let at_max = MonitorTestResult { raw_value: 20, ..at_min.clone() };
+        let above  = MonitorTestResult { raw_value: 21, ..at_min.clone() };
+        assert!(at_min.passed() && at_max.passed() && !above.passed());
+        assert!(above.to_string().ends_with("ÉCHEC"));
+    }
+
+    #[test]
+    fn records_are_nine_bytes_long() {
+        let mut response : Vec<u8> = vec![0x46];
+        response.extend(record(0x01, 0x01, 0x0b, 450, 100, 900));
+        response.extend(record(0x01, 0x85, 0x24, 3, 0, 5));
+        let results : Vec<MonitorTestResult> = decode_monitor_tests(&response).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!((results[0].obdmid, results[0].tid, results[0].raw_value, results[0].raw_min, results[0].raw_max), (0x01, 0x01, 450, 100, 900));
+        assert!(close(results[0].value(), 0.45) && results[0].unit() == Some("V"));
+        assert_eq!((results[1].tid, results[1].value()), (0x85, 3.0));
+
+        // Un enregistrement tronqué invalide toute la réponse
+        response.extend_from_slice(&[0x01, 0x02, 0x0b, 0x00, 0x01]);
+        assert_eq!(decode_monitor_tests(&response), Err(PidError::TooShort { expected: 28, received: 24 }));
+        assert_eq!(decode_monitor_tests(&[0x46]), Err(PidError::TooShort { expected: 10, received: 1 }));
+        assert_eq!(decode_monitor_tests(&[0x45, 0x01]), Err(PidError::ModeMismatch { expected: 0x46, received: 0x45 }));
+    }
+
+    #[test]
+    fn oxygen_sensor_constants_have_no_limits() {
+        let result : OxygenSensorTestResult = decode_oxygen_sensor_test(&[0x45, 0x01, 0x01, 0x5a]).unwrap();
+        assert_eq!(result.test, OxygenSensorTest::RichToLeanThreshold);
+        assert!(close(result.value(), 0.45));
+        assert_eq!((result.min(), result.max(), result.passed()), (None, None, None));
+        assert_eq!(result.test.unit(), Some("V"));
+    }
+
+    #[test]
+    fn oxygen_sensor_scalings_and_limits() {
+        // 0,004 s par bit pour les temps de basculement
+        let switch : OxygenSensorTestResult = decode_oxygen_sensor_test(&[0x45, 0x05, 0x02, 0x19, 0x0a, 0x32]).unwrap();
+        assert!(close(switch.value(), 0.1) && close(switch.min().unwrap(), 0.04) && close(switch.max().unwrap(), 0.2));
+        assert_eq!(switch.passed(), Some(true));
+
+        // 0,04 s par bit pour la période
+        let period : OxygenSensorTestResult = decode_oxygen_sensor_test(&[0x45, 0x0a, 0x03, 0x64, 0x00, 0x32]).unwrap();
+        assert!(close(period.value(), 4.0));
+        assert_eq!((period.test.unit(), period.passed()), (Some("s"), Some(false)));
+
+        // 0,005 V par bit pour les tensions, bornes incluses
+        let voltage : OxygenSensorTestResult = decode_oxygen_sensor_test(&[0x45, 0x07, 0x01, 0x14, 0x14, 0x28]).unwrap();
+        assert!(close(voltage.value(), 0.1));
+        assert_eq!(voltage.passed(), Some(true));
+
+        let manufacturer : OxygenSensorTestResult = decode_oxygen_sensor_test(&[0x45, 0x81, 0x01, 0x07]).unwrap();
+        assert_eq!((manufacturer.test, manufacturer.value(), manufacturer.test.unit()), (OxygenSensorTest::Manufacturer(0x81), 7.0, None));
+    }
+
+    #[test]
+    fn oxygen_sensor_test_ids_round_trip() {
+        for tid in 0x01..=0x0c {
+            assert_eq!(OxygenSensorTest::from_tid(tid).tid(), tid);
+        }
+    }
+
+    #[test]
+    fn oxygen_sensor_location_and_length() {
+        let result : OxygenSensorTestResult = decode_oxygen_sensor_test(&[0x45, 0x01, 0x06, 0x5a]).unwrap();
+        assert_eq!(result.location(OxygenSensorLayout::TwoBanks), Some(OxygenSensorLocation { bank: 2, sensor: 2 }));
+        assert_eq!(result.location(OxygenSensorLayout::FourBanks), Some(OxygenSensorLocation { bank: 3, sensor: 2 }));
+        let outside : OxygenSensorTestResult = decode_oxygen_sensor_test(&[0x45, 0x01, 0x09, 0x5a]).unwrap();
+        assert_eq!(outside.location(OxygenSensorLayout::TwoBanks), None);
+
+        assert_eq!(decode_oxygen_sensor_test(&[0x45, 0x01, 0x01]), Err(PidError::TooShort { expected: 4, received: 3 }));
+        assert_eq!(decode_oxygen_sensor_test(&[0x45, 0x05, 0x01, 0x19, 0x0a]), Err(PidError::TooLong { expected: 6, received: 5 }));
+        assert_eq!(decode_oxygen_sensor_test(&[0x45, 0x05, 0x01, 0x19, 0x0a, 0x32, 0x00]), Err(PidError::TooLong { expected: 6, received: 7 }));
+    }
+}
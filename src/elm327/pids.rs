@@ -1,5 +1,7 @@
 // Uses
+use std::fmt;
 use crate::elm327::decoder::*;
+use crate::elm327::dtc::Dtc;
 use crate::elm327::types::*;
 
 // Enums
@@ -9,6 +11,140 @@ pub enum ResultSize {
     Value(usize)
 }
 
+impl ResultSize {
+    pub fn check(&self, size: usize) -> Result<(), PidError> {
+        let (minimum, maximum) : (usize, usize) = match self {
+            ResultSize::Range(minimum, maximum) => (*minimum, *maximum),
+            ResultSize::Value(value)            => (*value, *value)
+        };
+        if size < minimum { return Err(PidError::TooShort { expected: minimum, received: size }); }
+        if size > maximum { return Err(PidError::TooLong  { expected: maximum, received: size }); }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PidError {
+    Empty,
+    TooShort { expected: usize, received: usize },
+    TooLong  { expected: usize, received: usize },
+    ModeMismatch { expected: u8, received: u8 },
+    PidMismatch  { expected: u8, received: u8 },
+    NegativeResponse { mode: u8, code: u8 },
+    Unsupported { mode: u8, pid: u8 }
+}
+
+impl fmt::Display for PidError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PidError::Empty                           => write!(f, "Réponse vide"),
+            PidError::TooShort { expected, received } => write!(f, "Réponse trop courte : {} octets reçus, {} attendus au minimum", received, expected),
+            PidError::TooLong  { expected, received } => write!(f, "Réponse trop longue : {} octets reçus, {} attendus au maximum", received, expected),
+            PidError::ModeMismatch { expected, received } => write!(f, "Mode inattendu : 0x{:02x} reçu, 0x{:02x} attendu", received, expected),
+            PidError::PidMismatch  { expected, received } => write!(f, "Pid inattendu : 0x{:02x} reçu, 0x{:02x} attendu", received, expected),
+            PidError::NegativeResponse { mode, code }     => write!(f, "Réponse négative au mode 0x{:02x} : code 0x{:02x}", mode, code),
+            PidError::Unsupported { mode, pid }           => write!(f, "Pid 0x{:02x} du mode 0x{:02x} inconnu", pid, mode)
+        }
+    }
+}
+
+impl std::error::Error for PidError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NegativeResponseCode {
+    //
+    // Codes des réponses négatives (0x7f, mode, code) définis par l'ISO 14229 / ISO 15031-5
+    //
+    GeneralReject,
+    ServiceNotSupported,
+    SubFunctionNotSupported,
+    IncorrectMessageLength,
+    BusyRepeatRequest,
+    ConditionsNotCorrect,
+    RequestSequenceError,
+    RequestOutOfRange,
+    SecurityAccessDenied,
+    ResponsePending,
+    Other(u8)
+}
+
+impl NegativeResponseCode {
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            0x10 => NegativeResponseCode::GeneralReject,
+            0x11 => NegativeResponseCode::ServiceNotSupported,
+            0x12 => NegativeResponseCode::SubFunctionNotSupported,
+            0x13 => NegativeResponseCode::IncorrectMessageLength,
+            0x21 => NegativeResponseCode::BusyRepeatRequest,
+            0x22 => NegativeResponseCode::ConditionsNotCorrect,
+            0x24 => NegativeResponseCode::RequestSequenceError,
+            0x31 => NegativeResponseCode::RequestOutOfRange,
+            0x33 => NegativeResponseCode::SecurityAccessDenied,
+            0x78 => NegativeResponseCode::ResponsePending,
+            _    => NegativeResponseCode::Other(code)
+        }
+    }
+
+    pub fn code(&self) -> u8 {
+        match self {
+            NegativeResponseCode::GeneralReject           => 0x10,
+            NegativeResponseCode::ServiceNotSupported     => 0x11,
+            NegativeResponseCode::SubFunctionNotSupported => 0x12,
+            NegativeResponseCode::IncorrectMessageLength  => 0x13,
+            NegativeResponseCode::BusyRepeatRequest       => 0x21,
+            NegativeResponseCode::ConditionsNotCorrect    => 0x22,
+            NegativeResponseCode::RequestSequenceError    => 0x24,
+            NegativeResponseCode::RequestOutOfRange       => 0x31,
+            NegativeResponseCode::SecurityAccessDenied    => 0x33,
+            NegativeResponseCode::ResponsePending         => 0x78,
+            NegativeResponseCode::Other(code)             => *code
+        }
+    }
+}
+
+impl fmt::Display for NegativeResponseCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NegativeResponseCode::GeneralReject           => write!(f, "Requête rejetée"),
+            NegativeResponseCode::ServiceNotSupported     => write!(f, "Service non supporté"),
+            NegativeResponseCode::SubFunctionNotSupported => write!(f, "Sous-fonction non supportée"),
+            NegativeResponseCode::IncorrectMessageLength  => write!(f, "Longueur de message incorrecte"),
+            NegativeResponseCode::BusyRepeatRequest       => write!(f, "Calculateur occupé, requête à répéter"),
+            NegativeResponseCode::ConditionsNotCorrect    => write!(f, "Conditions non remplies"),
+            NegativeResponseCode::RequestSequenceError    => write!(f, "Erreur de séquence"),
+            NegativeResponseCode::RequestOutOfRange       => write!(f, "Requête hors limites"),
+            NegativeResponseCode::SecurityAccessDenied    => write!(f, "Accès refusé"),
+            NegativeResponseCode::ResponsePending         => write!(f, "Réponse en attente"),
+            NegativeResponseCode::Other(code)             => write!(f, "Code 0x{:02x}", code)
+        }
+    }
+}
+
+// Conversion des octets de données vers le type d'entrée d'un pid
+pub trait FromBytes: Sized {
+    //
+    // Trait construisant l'entrée d'un pid à partir des octets de données (A, B, C, ...)
+    // :fn from_bytes: Méthode construisant la valeur, la taille ayant déjà été vérifiée
+    //
+    fn from_bytes(bytes: &[u8]) -> Self;
+}
+
+impl FromBytes for u8 {
+    fn from_bytes(bytes: &[u8]) -> Self { bytes.first().copied().unwrap_or(0) }
+}
+
+impl FromBytes for u16 {
+    fn from_bytes(bytes: &[u8]) -> Self { bytes.iter().take(2).fold(0, |acc, b| acc << 8 | *b as u16) }
+}
+
+impl FromBytes for u32 {
+    fn from_bytes(bytes: &[u8]) -> Self { bytes.iter().take(4).fold(0, |acc, b| acc << 8 | *b as u32) }
+}
+
+impl FromBytes for Vec<u8> {
+    fn from_bytes(bytes: &[u8]) -> Self { bytes.to_vec() }
+}
+
 // Définission du format d'un PID
 pub trait Pid {
     //
@@ -23,6 +159,9 @@ pub trait Pid {
     // :fn   max:              Valeur max d'entrée
     // :fn   unit:             Unitée dans laquelle est retournée le résultat
     // :fn   interpret_result: Méthode retournant le résultat interprété d'une entrée donnée en paramètres
+    // :fn   request:          Octets de la requête à envoyer au véhicule
+    // :fn   response_header:  Octets précédant les données dans la réponse (0x40+mode, pid)
+    // :fn   decode:           Méthode vérifiant puis interprétant une réponse brute
     //
     type Input;
     type Output;
@@ -37,6 +176,112 @@ pub trait Pid {
     fn to_string(&self) -> String {
         format!("Pid(mode={}, pid={}, result_size={:?})", self.mode_number(), self.pid_number(), self.result_size())
     }
+    fn request(&self) -> Vec<u8> {
+        vec![self.mode_number() as u8, self.pid_number() as u8]
+    }
+    fn response_header(&self) -> Vec<u8> {
+        vec![0x40 + self.mode_number() as u8, self.pid_number() as u8]
+    }
+    fn decode(&self, response: &[u8]) -> Result<Self::Output, PidError> where Self::Input: FromBytes {
+        let data : &[u8] = check_response(&self.response_header(), response)?;
+        self.result_size().check(data.len())?;
+        Ok(self.interpret_result(Self::Input::from_bytes(data)))
+    }
+}
+
+// Capteurs d'oxygène dont la position dépend de la disposition annoncée par le véhicule
+pub trait OxygenSensorPid {
+    //
+    // Trait implémenté par les pids propres à un capteur d'oxygène (OxygenSensorN, OxygenSensorLambdaN, OxygenSensorCurrentN)
+    // :fn sensor_index: Numéro du capteur, de 1 à 8
+    // :fn location:     Position physique du capteur selon la disposition des pids 0x13 / 0x1d
+    //
+    fn sensor_index(&self) -> u8;
+    fn location(&self, layout: OxygenSensorLayout) -> Option<OxygenSensorLocation> {
+        layout.location(self.sensor_index())
+    }
+}
+
+// Trames figées (mode 0x02)
+pub struct FreezeFrame<P: Pid> {
+    //
+    // Pid du mode 0x01 lu dans une trame figée : même encodage, précédé du numéro de trame
+    // :field pid:   Pid du mode 0x01 dont le décodeur est réutilisé
+    // :field frame: Numéro de la trame figée, 0 pour celle enregistrée par la norme
+    //
+    pid:   P,
+    frame: u8
+}
+
+impl<P: Pid> FreezeFrame<P> {
+    pub fn new(pid: P, frame: u8) -> Self {
+        FreezeFrame { pid, frame }
+    }
+
+    pub fn frame(&self) -> u8 {
+        self.frame
+    }
+
+    pub fn pid(&self) -> &P {
+        &self.pid
+    }
+}
+
+impl<P: Pid> Pid for FreezeFrame<P> {
+    type Input  = P::Input;
+    type Output = P::Output;
+    fn mode_number(&self) -> ModLen { 0x02 }
+    fn pid_number(&self)  -> PidLen { self.pid.pid_number() }
+    fn result_size(&self) -> ResultSize { self.pid.result_size() }
+    fn description(&self) -> &'static str { self.pid.description() }
+    fn min(&self)  -> Option<Self::Output>  { self.pid.min() }
+    fn max(&self)  -> Option<Self::Output>  { self.pid.max() }
+    fn unit(&self) -> Option<&'static str> { self.pid.unit() }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        self.pid.interpret_result(input)
+    }
+    fn request(&self) -> Vec<u8> {
+        vec![0x02, self.pid_number() as u8, self.frame]
+    }
+    fn response_header(&self) -> Vec<u8> {
+        vec![0x42, self.pid_number() as u8, self.frame]
+    }
+}
+
+// Fonctions
+pub fn freeze_frame_to_mode01(response: &[u8]) -> Result<(u8, Vec<u8>), PidError> {
+    //
+    // Convertit une réponse du mode 0x02 (0x42, pid, trame, données) en réponse du mode 0x01 (0x41, pid, données)
+    // :return: Numéro de la trame figée et réponse décodable par le pid du mode 0x01
+    //
+    let pid   : u8 = response.get(1).copied().unwrap_or(0);
+    let frame : u8 = response.get(2).copied().unwrap_or(0);
+    let data  : &[u8] = check_response(&[0x42, pid, frame], response)?;
+    let mut converted : Vec<u8> = vec![0x41, pid];
+    converted.extend_from_slice(data);
+    Ok((frame, converted))
+}
+
+pub fn check_response<'a>(header: &[u8], response: &'a [u8]) -> Result<&'a [u8], PidError> {
+    //
+    // Vérifie l'entête d'une réponse (mode + 0x40, pid, ...) et retourne les octets de données
+    //
+    let mode : u8 = *header.first().ok_or(PidError::Empty)?;
+    let received_mode : u8 = *response.first().ok_or(PidError::Empty)?;
+    if received_mode == 0x7f {
+        return Err(PidError::NegativeResponse {
+            mode: response.get(1).copied().unwrap_or(mode.wrapping_sub(0x40)),
+            code: response.get(2).copied().unwrap_or(0)
+        });
+    }
+    if received_mode != mode { return Err(PidError::ModeMismatch { expected: mode, received: received_mode }); }
+    if response.len() < header.len() {
+        return Err(PidError::TooShort { expected: header.len(), received: response.len() });
+    }
+    for (expected, received) in header.iter().zip(response.iter()).skip(1) {
+        if expected != received { return Err(PidError::PidMismatch { expected: *expected, received: *received }); }
+    }
+    Ok(&response[header.len()..])
 }
 
 
@@ -62,7 +307,7 @@ pub struct StatusSinceDTC;
 impl StatusSinceDTC { pub fn new() -> Self { StatusSinceDTC } }
 impl Pid for StatusSinceDTC {
     type Input  = u32;
-    type Output = u32;
+    type Output = MonitorStatus;
     fn mode_number(&self) -> ModLen { 0x01 }
     fn pid_number(&self)  -> PidLen { 0x01 }
     fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
@@ -71,7 +316,7 @@ impl Pid for StatusSinceDTC {
     fn max(&self)  -> Option<Self::Output>  { None }
     fn unit(&self) -> Option<&'static str> { None }
     fn interpret_result(&self, input: Self::Input) -> Self::Output {
-        input
+        decode_monitor_status(input)
     }
 }
 
@@ -79,15 +324,17 @@ pub struct FreezeDTC;
 impl FreezeDTC { pub fn new() -> Self { FreezeDTC } }
 impl Pid for FreezeDTC {
     type Input  = u16;
-    type Output = ();
+    type Output = Option<Dtc>;
     fn mode_number(&self) -> ModLen { 0x01 }
     fn pid_number(&self)  -> PidLen { 0x02 }
     fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
-    fn description(&self) -> &'static str { "Geler DTC" }
+    fn description(&self) -> &'static str { "DTC ayant provoqué l'enregistrement de la trame figée" }
     fn min(&self)  -> Option<Self::Output>  { None }
     fn max(&self)  -> Option<Self::Output>  { None }
     fn unit(&self) -> Option<&'static str> { None }
-    fn interpret_result(&self, _: Self::Input) -> Self::Output {}
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        Dtc::from_bytes((input >> 8) as u8, (input & 0xff) as u8)
+    }
 }
 
 pub struct FuelSystemStatus;
@@ -199,7 +446,7 @@ impl Pid for LongTermFuelTrim2 {
     type Input  = u8;
     type Output = f64;
     fn mode_number(&self) -> ModLen { 0x01 }
-    fn pid_number(&self)  -> PidLen { 0x07 }
+    fn pid_number(&self)  -> PidLen { 0x09 }
     fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
     fn description(&self) -> &'static str { "Trim de carburant à long terme, banque 2" }
     fn min(&self)  -> Option<Self::Output>  { Some(-100.0) }
@@ -367,7 +614,7 @@ pub struct OxygenSensorPresent;
 impl OxygenSensorPresent { pub fn new() -> Self { OxygenSensorPresent } }
 impl Pid for OxygenSensorPresent {
     type Input  = u8;
-    type Output = ();
+    type Output = Vec<OxygenSensorLocation>;
     fn mode_number(&self) -> ModLen { 0x01 }
     fn pid_number(&self)  -> PidLen { 0x13 }
     fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
@@ -375,9 +622,8 @@ impl Pid for OxygenSensorPresent {
     fn min(&self)  -> Option<Self::Output>  { None }
     fn max(&self)  -> Option<Self::Output>  { None }
     fn unit(&self) -> Option<&'static str> { None }
-    fn interpret_result(&self, _: Self::Input) -> Self::Output {
-        // TODO
-        ()
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensors_present(input, OxygenSensorLayout::TwoBanks)
     }
 }
 
@@ -538,7 +784,7 @@ pub struct OxygenSensorPresent4Banks;
 impl OxygenSensorPresent4Banks { pub fn new() -> Self { OxygenSensorPresent4Banks } }
 impl Pid for OxygenSensorPresent4Banks {
     type Input  = u8;
-    type Output = ();
+    type Output = Vec<OxygenSensorLocation>;
     fn mode_number(&self) -> ModLen { 0x01 }
     fn pid_number(&self)  -> PidLen { 0x1d }
     fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
@@ -546,9 +792,8 @@ impl Pid for OxygenSensorPresent4Banks {
     fn min(&self)  -> Option<Self::Output>  { None }
     fn max(&self)  -> Option<Self::Output>  { None }
     fn unit(&self) -> Option<&'static str> { None }
-    fn interpret_result(&self, _: Self::Input) -> Self::Output {
-        // TODO
-        ()
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensors_present(input, OxygenSensorLayout::FourBanks)
     }
 }
 
@@ -856,4 +1101,2329 @@ impl Pid for FuelTankLevelInput {
     fn interpret_result(&self, input: Self::Input) -> Self::Output {
         decode_percent(input)
     }
-}
\ No newline at end of file
+}
+
+pub struct WarmUpsSinceCodesCleared;
+impl WarmUpsSinceCodesCleared { pub fn new() -> Self { WarmUpsSinceCodesCleared } }
+impl Pid for WarmUpsSinceCodesCleared {
+    type Input  = u8;
+    type Output = u8;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x30 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Nombre de cycles de chauffe depuis l'effacement des DTC" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(255) }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        input
+    }
+}
+
+pub struct DistanceSinceCodesCleared;
+impl DistanceSinceCodesCleared { pub fn new() -> Self { DistanceSinceCodesCleared } }
+impl Pid for DistanceSinceCodesCleared {
+    type Input  = u16;
+    type Output = u16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x31 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Distance parcourue depuis l'effacement des DTC" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(65535) }
+    fn unit(&self) -> Option<&'static str> { Some("km") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_km(input)
+    }
+}
+
+pub struct EvapSystemVaporPressure;
+impl EvapSystemVaporPressure { pub fn new() -> Self { EvapSystemVaporPressure } }
+impl Pid for EvapSystemVaporPressure {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x32 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Pression de vapeur du système d'évaporation" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-8192.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(8191.75) }
+    fn unit(&self) -> Option<&'static str> { Some("Pa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_evap_vapor_pressure(input)
+    }
+}
+
+pub struct AbsoluteBarometricPressure;
+impl AbsoluteBarometricPressure { pub fn new() -> Self { AbsoluteBarometricPressure } }
+impl Pid for AbsoluteBarometricPressure {
+    type Input  = u8;
+    type Output = u8;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x33 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Pression barométrique absolue" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(255) }
+    fn unit(&self) -> Option<&'static str> { Some("kPa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        input
+    }
+}
+
+pub struct OxygenSensorCurrent1;
+impl OxygenSensorCurrent1 { pub fn new() -> Self { OxygenSensorCurrent1 } }
+impl Pid for OxygenSensorCurrent1 {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x34 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur d'oxygène 1, AB : rapport d'équivalence air-carburant (lambda,λ), CD : Courant" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, -128.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((2.0, 128.0)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, mA)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensor_current(input)
+    }
+}
+
+pub struct OxygenSensorCurrent2;
+impl OxygenSensorCurrent2 { pub fn new() -> Self { OxygenSensorCurrent2 } }
+impl Pid for OxygenSensorCurrent2 {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x35 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur d'oxygène 2, AB : rapport d'équivalence air-carburant (lambda,λ), CD : Courant" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, -128.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((2.0, 128.0)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, mA)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensor_current(input)
+    }
+}
+
+pub struct OxygenSensorCurrent3;
+impl OxygenSensorCurrent3 { pub fn new() -> Self { OxygenSensorCurrent3 } }
+impl Pid for OxygenSensorCurrent3 {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x36 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur d'oxygène 3, AB : rapport d'équivalence air-carburant (lambda,λ), CD : Courant" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, -128.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((2.0, 128.0)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, mA)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensor_current(input)
+    }
+}
+
+pub struct OxygenSensorCurrent4;
+impl OxygenSensorCurrent4 { pub fn new() -> Self { OxygenSensorCurrent4 } }
+impl Pid for OxygenSensorCurrent4 {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x37 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur d'oxygène 4, AB : rapport d'équivalence air-carburant (lambda,λ), CD : Courant" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, -128.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((2.0, 128.0)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, mA)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensor_current(input)
+    }
+}
+
+pub struct OxygenSensorCurrent5;
+impl OxygenSensorCurrent5 { pub fn new() -> Self { OxygenSensorCurrent5 } }
+impl Pid for OxygenSensorCurrent5 {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x38 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur d'oxygène 5, AB : rapport d'équivalence air-carburant (lambda,λ), CD : Courant" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, -128.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((2.0, 128.0)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, mA)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensor_current(input)
+    }
+}
+
+pub struct OxygenSensorCurrent6;
+impl OxygenSensorCurrent6 { pub fn new() -> Self { OxygenSensorCurrent6 } }
+impl Pid for OxygenSensorCurrent6 {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x39 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur d'oxygène 6, AB : rapport d'équivalence air-carburant (lambda,λ), CD : Courant" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, -128.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((2.0, 128.0)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, mA)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensor_current(input)
+    }
+}
+
+pub struct OxygenSensorCurrent7;
+impl OxygenSensorCurrent7 { pub fn new() -> Self { OxygenSensorCurrent7 } }
+impl Pid for OxygenSensorCurrent7 {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x3a }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur d'oxygène 7, AB : rapport d'équivalence air-carburant (lambda,λ), CD : Courant" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, -128.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((2.0, 128.0)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, mA)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensor_current(input)
+    }
+}
+
+pub struct OxygenSensorCurrent8;
+impl OxygenSensorCurrent8 { pub fn new() -> Self { OxygenSensorCurrent8 } }
+impl Pid for OxygenSensorCurrent8 {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x3b }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur d'oxygène 8, AB : rapport d'équivalence air-carburant (lambda,λ), CD : Courant" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, -128.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((2.0, 128.0)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, mA)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_oxygen_sensor_current(input)
+    }
+}
+
+pub struct CatalystTemperatureBank1Sensor1;
+impl CatalystTemperatureBank1Sensor1 { pub fn new() -> Self { CatalystTemperatureBank1Sensor1 } }
+impl Pid for CatalystTemperatureBank1Sensor1 {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x3c }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Température du catalyseur, banque 1, capteur 1" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-40.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(6513.5) }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_catalyst_temperature(input)
+    }
+}
+
+pub struct CatalystTemperatureBank2Sensor1;
+impl CatalystTemperatureBank2Sensor1 { pub fn new() -> Self { CatalystTemperatureBank2Sensor1 } }
+impl Pid for CatalystTemperatureBank2Sensor1 {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x3d }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Température du catalyseur, banque 2, capteur 1" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-40.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(6513.5) }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_catalyst_temperature(input)
+    }
+}
+
+pub struct CatalystTemperatureBank1Sensor2;
+impl CatalystTemperatureBank1Sensor2 { pub fn new() -> Self { CatalystTemperatureBank1Sensor2 } }
+impl Pid for CatalystTemperatureBank1Sensor2 {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x3e }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Température du catalyseur, banque 1, capteur 2" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-40.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(6513.5) }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_catalyst_temperature(input)
+    }
+}
+
+pub struct CatalystTemperatureBank2Sensor2;
+impl CatalystTemperatureBank2Sensor2 { pub fn new() -> Self { CatalystTemperatureBank2Sensor2 } }
+impl Pid for CatalystTemperatureBank2Sensor2 {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x3f }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Température du catalyseur, banque 2, capteur 2" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-40.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(6513.5) }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_catalyst_temperature(input)
+    }
+}
+
+pub struct AvailablePids60;
+impl AvailablePids60 { pub fn new() -> Self { AvailablePids60 } }
+impl Pid for AvailablePids60 {
+    type Input  = u32;
+    type Output = Vec<PidLen>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x40 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Pids supportés de 0x41 à 0x60" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_available_pids(input, 2)
+    }
+}
+
+pub struct MonitorStatusThisDriveCycle;
+impl MonitorStatusThisDriveCycle { pub fn new() -> Self { MonitorStatusThisDriveCycle } }
+impl Pid for MonitorStatusThisDriveCycle {
+    type Input  = u32;
+    type Output = DriveCycleMonitorStatus;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x41 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "État des moniteurs pendant ce cycle de conduite" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_drive_cycle_monitor_status(input)
+    }
+}
+
+pub struct ControlModuleVoltage;
+impl ControlModuleVoltage { pub fn new() -> Self { ControlModuleVoltage } }
+impl Pid for ControlModuleVoltage {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x42 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Tension du module de contrôle" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(65.535) }
+    fn unit(&self) -> Option<&'static str> { Some("V") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_control_module_voltage(input)
+    }
+}
+
+pub struct AbsoluteLoadValue;
+impl AbsoluteLoadValue { pub fn new() -> Self { AbsoluteLoadValue } }
+impl Pid for AbsoluteLoadValue {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x43 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Valeur de charge absolue" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(25700.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_absolute_load(input)
+    }
+}
+
+pub struct CommandedAirFuelEquivalenceRatio;
+impl CommandedAirFuelEquivalenceRatio { pub fn new() -> Self { CommandedAirFuelEquivalenceRatio } }
+impl Pid for CommandedAirFuelEquivalenceRatio {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x44 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Rapport d'équivalence air-carburant commandé (lambda,λ)" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(2.0) }
+    fn unit(&self) -> Option<&'static str> { Some("ratio") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_equivalence_ratio(input)
+    }
+}
+
+pub struct RelativeThrottlePosition;
+impl RelativeThrottlePosition { pub fn new() -> Self { RelativeThrottlePosition } }
+impl Pid for RelativeThrottlePosition {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x45 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Position relative du papillon" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct AmbientAirTemperature;
+impl AmbientAirTemperature { pub fn new() -> Self { AmbientAirTemperature } }
+impl Pid for AmbientAirTemperature {
+    type Input  = u8;
+    type Output = i16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x46 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Température de l'air ambiant" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-40) }
+    fn max(&self)  -> Option<Self::Output>  { Some(215) }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_celsius(input)
+    }
+}
+
+pub struct AbsoluteThrottlePositionB;
+impl AbsoluteThrottlePositionB { pub fn new() -> Self { AbsoluteThrottlePositionB } }
+impl Pid for AbsoluteThrottlePositionB {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x47 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Position absolue du papillon B" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct AbsoluteThrottlePositionC;
+impl AbsoluteThrottlePositionC { pub fn new() -> Self { AbsoluteThrottlePositionC } }
+impl Pid for AbsoluteThrottlePositionC {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x48 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Position absolue du papillon C" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct AcceleratorPedalPositionD;
+impl AcceleratorPedalPositionD { pub fn new() -> Self { AcceleratorPedalPositionD } }
+impl Pid for AcceleratorPedalPositionD {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x49 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Position de la pédale d'accélérateur D" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct AcceleratorPedalPositionE;
+impl AcceleratorPedalPositionE { pub fn new() -> Self { AcceleratorPedalPositionE } }
+impl Pid for AcceleratorPedalPositionE {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x4a }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Position de la pédale d'accélérateur E" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct AcceleratorPedalPositionF;
+impl AcceleratorPedalPositionF { pub fn new() -> Self { AcceleratorPedalPositionF } }
+impl Pid for AcceleratorPedalPositionF {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x4b }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Position de la pédale d'accélérateur F" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct CommandedThrottleActuator;
+impl CommandedThrottleActuator { pub fn new() -> Self { CommandedThrottleActuator } }
+impl Pid for CommandedThrottleActuator {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x4c }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Actionneur de papillon commandé" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct TimeRunWithMIL;
+impl TimeRunWithMIL { pub fn new() -> Self { TimeRunWithMIL } }
+impl Pid for TimeRunWithMIL {
+    type Input  = u16;
+    type Output = u16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x4d }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Temps de fonctionnement avec le témoin de dysfonctionnement (MIL) allumé" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(65535) }
+    fn unit(&self) -> Option<&'static str> { Some("minutes") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_word(input)
+    }
+}
+
+pub struct TimeSinceCodesCleared;
+impl TimeSinceCodesCleared { pub fn new() -> Self { TimeSinceCodesCleared } }
+impl Pid for TimeSinceCodesCleared {
+    type Input  = u16;
+    type Output = u16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x4e }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Temps écoulé depuis l'effacement des DTC" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(65535) }
+    fn unit(&self) -> Option<&'static str> { Some("minutes") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_word(input)
+    }
+}
+
+pub struct MaximumSensorValues;
+impl MaximumSensorValues { pub fn new() -> Self { MaximumSensorValues } }
+impl Pid for MaximumSensorValues {
+    type Input  = u32;
+    type Output = MaximumValues;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x4f }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Valeurs maximales du rapport d'équivalence, de la tension et du courant des capteurs d'oxygène et de la pression absolue du collecteur" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(ratio, V, mA, kPa)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_maximum_values(input)
+    }
+}
+
+pub struct MaximumAirFlowRate;
+impl MaximumAirFlowRate { pub fn new() -> Self { MaximumAirFlowRate } }
+impl Pid for MaximumAirFlowRate {
+    type Input  = u32;
+    type Output = u16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x50 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Débit maximal du capteur de débit d'air massique (MAF)" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(2550) }
+    fn unit(&self) -> Option<&'static str> { Some("grams/sec") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_maximum_air_flow(input)
+    }
+}
+
+pub struct FuelTypeUsed;
+impl FuelTypeUsed { pub fn new() -> Self { FuelTypeUsed } }
+impl Pid for FuelTypeUsed {
+    type Input  = u8;
+    type Output = FuelType;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x51 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Type de carburant" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_fuel_type(input)
+    }
+}
+
+pub struct EthanolFuelPercentage;
+impl EthanolFuelPercentage { pub fn new() -> Self { EthanolFuelPercentage } }
+impl Pid for EthanolFuelPercentage {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x52 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Pourcentage d'éthanol dans le carburant" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct AbsoluteEvapSystemVaporPressure;
+impl AbsoluteEvapSystemVaporPressure { pub fn new() -> Self { AbsoluteEvapSystemVaporPressure } }
+impl Pid for AbsoluteEvapSystemVaporPressure {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x53 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Pression absolue de vapeur du système d'évaporation" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(327.675) }
+    fn unit(&self) -> Option<&'static str> { Some("kPa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_absolute_evap_pressure(input)
+    }
+}
+
+pub struct EvapSystemVaporPressureWide;
+impl EvapSystemVaporPressureWide { pub fn new() -> Self { EvapSystemVaporPressureWide } }
+impl Pid for EvapSystemVaporPressureWide {
+    type Input  = u16;
+    type Output = i16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x54 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Pression de vapeur du système d'évaporation (plage étendue)" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-32768) }
+    fn max(&self)  -> Option<Self::Output>  { Some(32767) }
+    fn unit(&self) -> Option<&'static str> { Some("Pa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_evap_vapor_pressure_wide(input)
+    }
+}
+
+pub struct ShortTermSecondaryOxygenTrimBanks13;
+impl ShortTermSecondaryOxygenTrimBanks13 { pub fn new() -> Self { ShortTermSecondaryOxygenTrimBanks13 } }
+impl Pid for ShortTermSecondaryOxygenTrimBanks13 {
+    type Input  = u16;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x55 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Trim secondaire du capteur d'oxygène à court terme, banques 1 et 3" }
+    fn min(&self)  -> Option<Self::Output>  { Some((-100.0, -100.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((99.2, 99.2)) }
+    fn unit(&self) -> Option<&'static str> { Some("(%, %)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_secondary_oxygen_trim(input)
+    }
+}
+
+pub struct LongTermSecondaryOxygenTrimBanks13;
+impl LongTermSecondaryOxygenTrimBanks13 { pub fn new() -> Self { LongTermSecondaryOxygenTrimBanks13 } }
+impl Pid for LongTermSecondaryOxygenTrimBanks13 {
+    type Input  = u16;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x56 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Trim secondaire du capteur d'oxygène à long terme, banques 1 et 3" }
+    fn min(&self)  -> Option<Self::Output>  { Some((-100.0, -100.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((99.2, 99.2)) }
+    fn unit(&self) -> Option<&'static str> { Some("(%, %)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_secondary_oxygen_trim(input)
+    }
+}
+
+pub struct ShortTermSecondaryOxygenTrimBanks24;
+impl ShortTermSecondaryOxygenTrimBanks24 { pub fn new() -> Self { ShortTermSecondaryOxygenTrimBanks24 } }
+impl Pid for ShortTermSecondaryOxygenTrimBanks24 {
+    type Input  = u16;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x57 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Trim secondaire du capteur d'oxygène à court terme, banques 2 et 4" }
+    fn min(&self)  -> Option<Self::Output>  { Some((-100.0, -100.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((99.2, 99.2)) }
+    fn unit(&self) -> Option<&'static str> { Some("(%, %)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_secondary_oxygen_trim(input)
+    }
+}
+
+pub struct LongTermSecondaryOxygenTrimBanks24;
+impl LongTermSecondaryOxygenTrimBanks24 { pub fn new() -> Self { LongTermSecondaryOxygenTrimBanks24 } }
+impl Pid for LongTermSecondaryOxygenTrimBanks24 {
+    type Input  = u16;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x58 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Trim secondaire du capteur d'oxygène à long terme, banques 2 et 4" }
+    fn min(&self)  -> Option<Self::Output>  { Some((-100.0, -100.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((99.2, 99.2)) }
+    fn unit(&self) -> Option<&'static str> { Some("(%, %)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_secondary_oxygen_trim(input)
+    }
+}
+
+pub struct FuelRailAbsolutePressure;
+impl FuelRailAbsolutePressure { pub fn new() -> Self { FuelRailAbsolutePressure } }
+impl Pid for FuelRailAbsolutePressure {
+    type Input  = u16;
+    type Output = u32;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x59 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Pression absolue de la rampe de carburant" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(655350) }
+    fn unit(&self) -> Option<&'static str> { Some("kPa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_fuel_rail_gauge_pressure(input)
+    }
+}
+
+pub struct RelativeAcceleratorPedalPosition;
+impl RelativeAcceleratorPedalPosition { pub fn new() -> Self { RelativeAcceleratorPedalPosition } }
+impl Pid for RelativeAcceleratorPedalPosition {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x5a }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Position relative de la pédale d'accélérateur" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct HybridBatteryPackRemainingLife;
+impl HybridBatteryPackRemainingLife { pub fn new() -> Self { HybridBatteryPackRemainingLife } }
+impl Pid for HybridBatteryPackRemainingLife {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x5b }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Durée de vie restante de la batterie hybride" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct EngineOilTemperature;
+impl EngineOilTemperature { pub fn new() -> Self { EngineOilTemperature } }
+impl Pid for EngineOilTemperature {
+    type Input  = u8;
+    type Output = i16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x5c }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Température de l'huile moteur" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-40) }
+    fn max(&self)  -> Option<Self::Output>  { Some(210) }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_celsius(input)
+    }
+}
+
+pub struct FuelInjectionTiming;
+impl FuelInjectionTiming { pub fn new() -> Self { FuelInjectionTiming } }
+impl Pid for FuelInjectionTiming {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x5d }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Calage de l'injection de carburant" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-210.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(301.992) }
+    fn unit(&self) -> Option<&'static str> { Some("°") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_injection_timing(input)
+    }
+}
+
+pub struct EngineFuelRate;
+impl EngineFuelRate { pub fn new() -> Self { EngineFuelRate } }
+impl Pid for EngineFuelRate {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x5e }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Débit de carburant du moteur" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(3212.75) }
+    fn unit(&self) -> Option<&'static str> { Some("L/h") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_fuel_rate(input)
+    }
+}
+
+pub struct EmissionRequirementsForThisVehicle;
+impl EmissionRequirementsForThisVehicle { pub fn new() -> Self { EmissionRequirementsForThisVehicle } }
+impl Pid for EmissionRequirementsForThisVehicle {
+    type Input  = u8;
+    type Output = EmissionRequirements;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x5f }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Exigences d'émissions pour lesquelles le véhicule est conçu" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_emission_requirements(input)
+    }
+}
+
+pub struct AvailablePids80;
+impl AvailablePids80 { pub fn new() -> Self { AvailablePids80 } }
+impl Pid for AvailablePids80 {
+    type Input  = u32;
+    type Output = Vec<PidLen>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x60 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Pids supportés de 0x61 à 0x80" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_available_pids(input, 3)
+    }
+}
+
+pub struct DriverDemandEnginePercentTorque;
+impl DriverDemandEnginePercentTorque { pub fn new() -> Self { DriverDemandEnginePercentTorque } }
+impl Pid for DriverDemandEnginePercentTorque {
+    type Input  = u8;
+    type Output = i16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x61 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Couple moteur demandé par le conducteur" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-125) }
+    fn max(&self)  -> Option<Self::Output>  { Some(130) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent_torque(input)
+    }
+}
+
+pub struct ActualEnginePercentTorque;
+impl ActualEnginePercentTorque { pub fn new() -> Self { ActualEnginePercentTorque } }
+impl Pid for ActualEnginePercentTorque {
+    type Input  = u8;
+    type Output = i16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x62 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Couple moteur réel" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-125) }
+    fn max(&self)  -> Option<Self::Output>  { Some(130) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent_torque(input)
+    }
+}
+
+pub struct EngineReferenceTorque;
+impl EngineReferenceTorque { pub fn new() -> Self { EngineReferenceTorque } }
+impl Pid for EngineReferenceTorque {
+    type Input  = u16;
+    type Output = u16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x63 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Couple de référence du moteur" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(65535) }
+    fn unit(&self) -> Option<&'static str> { Some("Nm") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_word(input)
+    }
+}
+
+pub struct EnginePercentTorqueData;
+impl EnginePercentTorqueData { pub fn new() -> Self { EnginePercentTorqueData } }
+impl Pid for EnginePercentTorqueData {
+    type Input  = Vec<u8>;
+    type Output = Vec<i16>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x64 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Couple moteur au ralenti puis aux points 1 à 4" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_engine_percent_torque_data(input)
+    }
+}
+
+pub struct AuxiliaryInputOutputSupported;
+impl AuxiliaryInputOutputSupported { pub fn new() -> Self { AuxiliaryInputOutputSupported } }
+impl Pid for AuxiliaryInputOutputSupported {
+    type Input  = u16;
+    type Output = AuxiliaryInputOutput;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x65 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Entrées / sorties auxiliaires (prise de force, rapport engagé, témoin de préchauffage)" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_auxiliary_input_output(input)
+    }
+}
+
+pub struct MassAirFlowSensors;
+impl MassAirFlowSensors { pub fn new() -> Self { MassAirFlowSensors } }
+impl Pid for MassAirFlowSensors {
+    type Input  = Vec<u8>;
+    type Output = (Option<f64>, Option<f64>);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x66 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Débit d'air des capteurs de débit d'air massique (MAF) A et B" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(grams/sec, grams/sec)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_maf_sensors(input)
+    }
+}
+
+pub struct EngineCoolantTemperatureSensors;
+impl EngineCoolantTemperatureSensors { pub fn new() -> Self { EngineCoolantTemperatureSensors } }
+impl Pid for EngineCoolantTemperatureSensors {
+    type Input  = Vec<u8>;
+    type Output = (Option<i16>, Option<i16>);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x67 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x03) }
+    fn description(&self) -> &'static str { "Température du liquide de refroidissement, capteurs 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(°C, °C)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_temperature_sensor_pair(input)
+    }
+}
+
+pub struct IntakeAirTemperatureSensors;
+impl IntakeAirTemperatureSensors { pub fn new() -> Self { IntakeAirTemperatureSensors } }
+impl Pid for IntakeAirTemperatureSensors {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<i16>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x68 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x07) }
+    fn description(&self) -> &'static str { "Température de l'air d'admission, capteurs 1 à 3 des banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_temperature_sensors(input)
+    }
+}
+
+pub struct CommandedEGRAndEGRError;
+impl CommandedEGRAndEGRError { pub fn new() -> Self { CommandedEGRAndEGRError } }
+impl Pid for CommandedEGRAndEGRError {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x69 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x07) }
+    fn description(&self) -> &'static str { "EGR A et B : rapport cyclique commandé, rapport cyclique réel et erreur" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_egr_sensors(input)
+    }
+}
+
+pub struct CommandedDieselIntakeAirFlowControl;
+impl CommandedDieselIntakeAirFlowControl { pub fn new() -> Self { CommandedDieselIntakeAirFlowControl } }
+impl Pid for CommandedDieselIntakeAirFlowControl {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x6a }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Contrôle du débit d'air d'admission diesel A et B : commande et position relative" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent_sensors(input)
+    }
+}
+
+pub struct ExhaustGasRecirculationTemperature;
+impl ExhaustGasRecirculationTemperature { pub fn new() -> Self { ExhaustGasRecirculationTemperature } }
+impl Pid for ExhaustGasRecirculationTemperature {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<i16>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x6b }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Température de recirculation des gaz d'échappement, capteurs 1 et 2 des banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_egr_temperatures(input)
+    }
+}
+
+pub struct CommandedThrottleActuatorControl;
+impl CommandedThrottleActuatorControl { pub fn new() -> Self { CommandedThrottleActuatorControl } }
+impl Pid for CommandedThrottleActuatorControl {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x6c }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Actionneur de papillon A et B : commande et position relative" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent_sensors(input)
+    }
+}
+
+pub struct FuelPressureControlSystem;
+impl FuelPressureControlSystem { pub fn new() -> Self { FuelPressureControlSystem } }
+impl Pid for FuelPressureControlSystem {
+    type Input  = Vec<u8>;
+    type Output = FuelPressureControl;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x6d }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x0b) }
+    fn description(&self) -> &'static str { "Système de contrôle de la pression de carburant A et B" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(kPa, kPa, °C)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_fuel_pressure_control(input)
+    }
+}
+
+pub struct InjectionPressureControlSystem;
+impl InjectionPressureControlSystem { pub fn new() -> Self { InjectionPressureControlSystem } }
+impl Pid for InjectionPressureControlSystem {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<u32>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x6e }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Système de contrôle de la pression d'injection A et B : commande et mesure" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("kPa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_pressure_sensors(input)
+    }
+}
+
+pub struct TurbochargerCompressorInletPressure;
+impl TurbochargerCompressorInletPressure { pub fn new() -> Self { TurbochargerCompressorInletPressure } }
+impl Pid for TurbochargerCompressorInletPressure {
+    type Input  = Vec<u8>;
+    type Output = (Option<u8>, Option<u8>);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x6f }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x03) }
+    fn description(&self) -> &'static str { "Pression d'entrée du compresseur du turbo, capteurs A et B" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(kPa, kPa)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_turbo_inlet_pressure(input)
+    }
+}
+
+pub struct BoostPressureControlSystem;
+impl BoostPressureControlSystem { pub fn new() -> Self { BoostPressureControlSystem } }
+impl Pid for BoostPressureControlSystem {
+    type Input  = Vec<u8>;
+    type Output = BoostPressureControl;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x70 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x0a) }
+    fn description(&self) -> &'static str { "Contrôle de la pression de suralimentation A et B" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("kPa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_boost_pressure_control(input)
+    }
+}
+
+pub struct VariableGeometryTurboControlSystem;
+impl VariableGeometryTurboControlSystem { pub fn new() -> Self { VariableGeometryTurboControlSystem } }
+impl Pid for VariableGeometryTurboControlSystem {
+    type Input  = Vec<u8>;
+    type Output = VariableGeometryTurboControl;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x71 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x06) }
+    fn description(&self) -> &'static str { "Contrôle du turbo à géométrie variable (VGT) A et B" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_variable_geometry_turbo_control(input)
+    }
+}
+
+pub struct WastegateControl;
+impl WastegateControl { pub fn new() -> Self { WastegateControl } }
+impl Pid for WastegateControl {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x72 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Contrôle de la soupape de décharge A et B : commande et position" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent_sensors(input)
+    }
+}
+
+pub struct ExhaustPressure;
+impl ExhaustPressure { pub fn new() -> Self { ExhaustPressure } }
+impl Pid for ExhaustPressure {
+    type Input  = Vec<u8>;
+    type Output = (Option<f64>, Option<f64>);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x73 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Pression d'échappement, banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(kPa, kPa)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_exhaust_pressure(input)
+    }
+}
+
+pub struct TurbochargerRpm;
+impl TurbochargerRpm { pub fn new() -> Self { TurbochargerRpm } }
+impl Pid for TurbochargerRpm {
+    type Input  = Vec<u8>;
+    type Output = (Option<u32>, Option<u32>);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x74 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Régime des turbos A et B" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(rpm, rpm)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_turbocharger_rpm(input)
+    }
+}
+
+pub struct TurbochargerATemperature;
+impl TurbochargerATemperature { pub fn new() -> Self { TurbochargerATemperature } }
+impl Pid for TurbochargerATemperature {
+    type Input  = Vec<u8>;
+    type Output = TurbochargerTemperature;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x75 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x07) }
+    fn description(&self) -> &'static str { "Températures du turbo A (entrée/sortie compresseur et turbine)" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_turbocharger_temperature(input)
+    }
+}
+
+pub struct TurbochargerBTemperature;
+impl TurbochargerBTemperature { pub fn new() -> Self { TurbochargerBTemperature } }
+impl Pid for TurbochargerBTemperature {
+    type Input  = Vec<u8>;
+    type Output = TurbochargerTemperature;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x76 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x07) }
+    fn description(&self) -> &'static str { "Températures du turbo B (entrée/sortie compresseur et turbine)" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_turbocharger_temperature(input)
+    }
+}
+
+pub struct ChargeAirCoolerTemperature;
+impl ChargeAirCoolerTemperature { pub fn new() -> Self { ChargeAirCoolerTemperature } }
+impl Pid for ChargeAirCoolerTemperature {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<i16>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x77 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Température du refroidisseur d'air de suralimentation, capteurs 1 et 2 des banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_temperature_sensors(input)
+    }
+}
+
+pub struct ExhaustGasTemperatureBank1;
+impl ExhaustGasTemperatureBank1 { pub fn new() -> Self { ExhaustGasTemperatureBank1 } }
+impl Pid for ExhaustGasTemperatureBank1 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x78 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Température des gaz d'échappement, capteurs 1 à 4 de la banque 1" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_wide_temperature_sensors(input)
+    }
+}
+
+pub struct ExhaustGasTemperatureBank2;
+impl ExhaustGasTemperatureBank2 { pub fn new() -> Self { ExhaustGasTemperatureBank2 } }
+impl Pid for ExhaustGasTemperatureBank2 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x79 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Température des gaz d'échappement, capteurs 1 à 4 de la banque 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_wide_temperature_sensors(input)
+    }
+}
+
+pub struct DieselParticulateFilter1;
+impl DieselParticulateFilter1 { pub fn new() -> Self { DieselParticulateFilter1 } }
+impl Pid for DieselParticulateFilter1 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x7a }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x07) }
+    fn description(&self) -> &'static str { "Filtre à particules, banque 1 : pression différentielle, d'entrée et de sortie" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("kPa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_particulate_filter_pressure(input)
+    }
+}
+
+pub struct DieselParticulateFilter2;
+impl DieselParticulateFilter2 { pub fn new() -> Self { DieselParticulateFilter2 } }
+impl Pid for DieselParticulateFilter2 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x7b }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x07) }
+    fn description(&self) -> &'static str { "Filtre à particules, banque 2 : pression différentielle, d'entrée et de sortie" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("kPa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_particulate_filter_pressure(input)
+    }
+}
+
+pub struct DieselParticulateFilterTemperature;
+impl DieselParticulateFilterTemperature { pub fn new() -> Self { DieselParticulateFilterTemperature } }
+impl Pid for DieselParticulateFilterTemperature {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x7c }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Température d'entrée et de sortie du filtre à particules, banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_wide_temperature_sensors(input)
+    }
+}
+
+pub struct NoxNteControlAreaStatus;
+impl NoxNteControlAreaStatus { pub fn new() -> Self { NoxNteControlAreaStatus } }
+impl Pid for NoxNteControlAreaStatus {
+    type Input  = u8;
+    type Output = NteStatus;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x7d }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "État de la zone de contrôle NTE des NOx" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_nte_status(input)
+    }
+}
+
+pub struct PmNteControlAreaStatus;
+impl PmNteControlAreaStatus { pub fn new() -> Self { PmNteControlAreaStatus } }
+impl Pid for PmNteControlAreaStatus {
+    type Input  = u8;
+    type Output = NteStatus;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x7e }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "État de la zone de contrôle NTE des particules" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_nte_status(input)
+    }
+}
+
+pub struct EngineRunTime;
+impl EngineRunTime { pub fn new() -> Self { EngineRunTime } }
+impl Pid for EngineRunTime {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<u32>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x7f }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x0d) }
+    fn description(&self) -> &'static str { "Temps de fonctionnement du moteur : total, au ralenti et avec prise de force" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("seconds") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_run_times(input)
+    }
+}
+
+pub struct AvailablePidsA0;
+impl AvailablePidsA0 { pub fn new() -> Self { AvailablePidsA0 } }
+impl Pid for AvailablePidsA0 {
+    type Input  = u32;
+    type Output = Vec<PidLen>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x80 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Pids supportés de 0x81 à 0xa0" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_available_pids(input, 4)
+    }
+}
+
+pub struct EngineRunTimeForAecd1;
+impl EngineRunTimeForAecd1 { pub fn new() -> Self { EngineRunTimeForAecd1 } }
+impl Pid for EngineRunTimeForAecd1 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<u32>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x81 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x15) }
+    fn description(&self) -> &'static str { "Temps de fonctionnement avec les dispositifs auxiliaires de contrôle des émissions (AECD) 1 à 5 actifs" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("seconds") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_run_times(input)
+    }
+}
+
+pub struct EngineRunTimeForAecd2;
+impl EngineRunTimeForAecd2 { pub fn new() -> Self { EngineRunTimeForAecd2 } }
+impl Pid for EngineRunTimeForAecd2 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<u32>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x82 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x15) }
+    fn description(&self) -> &'static str { "Temps de fonctionnement avec les dispositifs auxiliaires de contrôle des émissions (AECD) 6 à 10 actifs" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("seconds") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_run_times(input)
+    }
+}
+
+pub struct NoxSensor;
+impl NoxSensor { pub fn new() -> Self { NoxSensor } }
+impl Pid for NoxSensor {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<u16>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x83 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Concentration des capteurs NOx, capteurs 1 et 2 des banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("ppm") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_nox_sensors(input)
+    }
+}
+
+pub struct ManifoldSurfaceTemperature;
+impl ManifoldSurfaceTemperature { pub fn new() -> Self { ManifoldSurfaceTemperature } }
+impl Pid for ManifoldSurfaceTemperature {
+    type Input  = u8;
+    type Output = i16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x84 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Température de surface du collecteur" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-40) }
+    fn max(&self)  -> Option<Self::Output>  { Some(215) }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_celsius(input)
+    }
+}
+
+pub struct NoxReagentSystemStatus;
+impl NoxReagentSystemStatus { pub fn new() -> Self { NoxReagentSystemStatus } }
+impl Pid for NoxReagentSystemStatus {
+    type Input  = Vec<u8>;
+    type Output = NoxReagentSystem;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x85 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x0a) }
+    fn description(&self) -> &'static str { "Système de réactif NOx : consommation moyenne, consommation demandée, niveau du réservoir et minuterie d'alerte" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(L/h, L/h, %, seconds)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_nox_reagent_system(input)
+    }
+}
+
+pub struct ParticulateMatterSensor;
+impl ParticulateMatterSensor { pub fn new() -> Self { ParticulateMatterSensor } }
+impl Pid for ParticulateMatterSensor {
+    type Input  = Vec<u8>;
+    type Output = (Option<f64>, Option<f64>);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x86 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Concentration des capteurs de particules, banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(mg/m³, mg/m³)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_particulate_matter_sensors(input)
+    }
+}
+
+pub struct IntakeManifoldAbsolutePressureSensors;
+impl IntakeManifoldAbsolutePressureSensors { pub fn new() -> Self { IntakeManifoldAbsolutePressureSensors } }
+impl Pid for IntakeManifoldAbsolutePressureSensors {
+    type Input  = Vec<u8>;
+    type Output = (Option<f64>, Option<f64>);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x87 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Pression absolue du collecteur d'admission, capteurs A et B" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(kPa, kPa)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_intake_manifold_pressure_sensors(input)
+    }
+}
+
+pub struct ScrInducementSystem;
+impl ScrInducementSystem { pub fn new() -> Self { ScrInducementSystem } }
+impl Pid for ScrInducementSystem {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x88 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x0d) }
+    fn description(&self) -> &'static str { "Système d'incitation SCR : état et distances parcourues avec l'incitation active" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 1)
+    }
+}
+
+pub struct EngineRunTimeForAecd3;
+impl EngineRunTimeForAecd3 { pub fn new() -> Self { EngineRunTimeForAecd3 } }
+impl Pid for EngineRunTimeForAecd3 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<u32>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x89 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x15) }
+    fn description(&self) -> &'static str { "Temps de fonctionnement avec les dispositifs auxiliaires de contrôle des émissions (AECD) 11 à 15 actifs" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("seconds") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_run_times(input)
+    }
+}
+
+pub struct EngineRunTimeForAecd4;
+impl EngineRunTimeForAecd4 { pub fn new() -> Self { EngineRunTimeForAecd4 } }
+impl Pid for EngineRunTimeForAecd4 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<u32>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x8a }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x15) }
+    fn description(&self) -> &'static str { "Temps de fonctionnement avec les dispositifs auxiliaires de contrôle des émissions (AECD) 16 à 20 actifs" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("seconds") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_run_times(input)
+    }
+}
+
+pub struct DieselAftertreatment;
+impl DieselAftertreatment { pub fn new() -> Self { DieselAftertreatment } }
+impl Pid for DieselAftertreatment {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x8b }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x07) }
+    fn description(&self) -> &'static str { "État du post-traitement diesel (régénération du filtre à particules, ...)" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 1)
+    }
+}
+
+pub struct OxygenSensorWideRange;
+impl OxygenSensorWideRange { pub fn new() -> Self { OxygenSensorWideRange } }
+impl Pid for OxygenSensorWideRange {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x8c }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x11) }
+    fn description(&self) -> &'static str { "Capteurs d'oxygène à large bande" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 1)
+    }
+}
+
+pub struct RelativeThrottlePositionG;
+impl RelativeThrottlePositionG { pub fn new() -> Self { RelativeThrottlePositionG } }
+impl Pid for RelativeThrottlePositionG {
+    type Input  = u8;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x8d }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Position relative du papillon G" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(100.0) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent(input)
+    }
+}
+
+pub struct EngineFrictionPercentTorque;
+impl EngineFrictionPercentTorque { pub fn new() -> Self { EngineFrictionPercentTorque } }
+impl Pid for EngineFrictionPercentTorque {
+    type Input  = u8;
+    type Output = i16;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x8e }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x01) }
+    fn description(&self) -> &'static str { "Couple de friction du moteur" }
+    fn min(&self)  -> Option<Self::Output>  { Some(-125) }
+    fn max(&self)  -> Option<Self::Output>  { Some(130) }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent_torque(input)
+    }
+}
+
+pub struct ParticulateMatterSensorOutput;
+impl ParticulateMatterSensorOutput { pub fn new() -> Self { ParticulateMatterSensorOutput } }
+impl Pid for ParticulateMatterSensorOutput {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x8f }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x07) }
+    fn description(&self) -> &'static str { "Sortie des capteurs de particules, banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 1)
+    }
+}
+
+pub struct WwhObdVehicleInformation;
+impl WwhObdVehicleInformation { pub fn new() -> Self { WwhObdVehicleInformation } }
+impl Pid for WwhObdVehicleInformation {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x90 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x03) }
+    fn description(&self) -> &'static str { "Informations du système OBD du véhicule (WWH-OBD)" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 1)
+    }
+}
+
+pub struct WwhObdCountersInformation;
+impl WwhObdCountersInformation { pub fn new() -> Self { WwhObdCountersInformation } }
+impl Pid for WwhObdCountersInformation {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x91 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x05) }
+    fn description(&self) -> &'static str { "Compteurs OBD du véhicule (WWH-OBD)" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 1)
+    }
+}
+
+pub struct FuelSystemControl;
+impl FuelSystemControl { pub fn new() -> Self { FuelSystemControl } }
+impl Pid for FuelSystemControl {
+    type Input  = u16;
+    type Output = Vec<Option<bool>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x92 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Contrôle du système de carburant" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_status(input)
+    }
+}
+
+pub struct WwhObdCountersSupport;
+impl WwhObdCountersSupport { pub fn new() -> Self { WwhObdCountersSupport } }
+impl Pid for WwhObdCountersSupport {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x93 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x03) }
+    fn description(&self) -> &'static str { "Compteurs OBD supportés (WWH-OBD)" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 3)
+    }
+}
+
+pub struct NoxWarningAndInducementSystem;
+impl NoxWarningAndInducementSystem { pub fn new() -> Self { NoxWarningAndInducementSystem } }
+impl Pid for NoxWarningAndInducementSystem {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x94 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x0c) }
+    fn description(&self) -> &'static str { "Système d'alerte et d'incitation NOx" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 1)
+    }
+}
+
+pub struct ExhaustGasTemperatureSensorsBank1;
+impl ExhaustGasTemperatureSensorsBank1 { pub fn new() -> Self { ExhaustGasTemperatureSensorsBank1 } }
+impl Pid for ExhaustGasTemperatureSensorsBank1 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x98 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Température des gaz d'échappement, capteurs 5 à 8 de la banque 1" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_wide_temperature_sensors(input)
+    }
+}
+
+pub struct ExhaustGasTemperatureSensorsBank2;
+impl ExhaustGasTemperatureSensorsBank2 { pub fn new() -> Self { ExhaustGasTemperatureSensorsBank2 } }
+impl Pid for ExhaustGasTemperatureSensorsBank2 {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x99 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Température des gaz d'échappement, capteurs 5 à 8 de la banque 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("°C") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_wide_temperature_sensors(input)
+    }
+}
+
+pub struct HybridVehicleSystemData;
+impl HybridVehicleSystemData { pub fn new() -> Self { HybridVehicleSystemData } }
+impl Pid for HybridVehicleSystemData {
+    type Input  = Vec<u8>;
+    type Output = HybridSystemData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x9a }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x06) }
+    fn description(&self) -> &'static str { "Système hybride / électrique : état de charge, tension et courant de la batterie" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(mode, V, A)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_hybrid_system_data(input)
+    }
+}
+
+pub struct DieselExhaustFluidSensorData;
+impl DieselExhaustFluidSensorData { pub fn new() -> Self { DieselExhaustFluidSensorData } }
+impl Pid for DieselExhaustFluidSensorData {
+    type Input  = u32;
+    type Output = DieselExhaustFluidData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x9b }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Capteur de fluide d'échappement diesel : concentration, température et niveau du réservoir" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(%, °C, %)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_diesel_exhaust_fluid(input)
+    }
+}
+
+pub struct OxygenSensorData;
+impl OxygenSensorData { pub fn new() -> Self { OxygenSensorData } }
+impl Pid for OxygenSensorData {
+    type Input  = Vec<u8>;
+    type Output = SupportedData;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x9c }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x11) }
+    fn description(&self) -> &'static str { "Données des capteurs d'oxygène" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_support_bytes(input, 1)
+    }
+}
+
+pub struct EngineFuelRateMass;
+impl EngineFuelRateMass { pub fn new() -> Self { EngineFuelRateMass } }
+impl Pid for EngineFuelRateMass {
+    type Input  = u32;
+    type Output = (f64, f64);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x9d }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Débit de carburant du moteur et du véhicule" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0.0, 0.0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((1310.7, 1310.7)) }
+    fn unit(&self) -> Option<&'static str> { Some("(grams/sec, grams/sec)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_engine_fuel_rates(input)
+    }
+}
+
+pub struct EngineExhaustFlowRate;
+impl EngineExhaustFlowRate { pub fn new() -> Self { EngineExhaustFlowRate } }
+impl Pid for EngineExhaustFlowRate {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x9e }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Débit des gaz d'échappement du moteur" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(13107.0) }
+    fn unit(&self) -> Option<&'static str> { Some("kg/h") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_exhaust_flow_rate(input)
+    }
+}
+
+pub struct FuelSystemPercentageUse;
+impl FuelSystemPercentageUse { pub fn new() -> Self { FuelSystemPercentageUse } }
+impl Pid for FuelSystemPercentageUse {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<f64>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0x9f }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Pourcentage d'utilisation des systèmes de carburant" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_percent_sensors(input)
+    }
+}
+
+pub struct AvailablePidsC0;
+impl AvailablePidsC0 { pub fn new() -> Self { AvailablePidsC0 } }
+impl Pid for AvailablePidsC0 {
+    type Input  = u32;
+    type Output = Vec<PidLen>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa0 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Pids supportés de 0xa1 à 0xc0" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_available_pids(input, 5)
+    }
+}
+
+pub struct NoxSensorCorrectedConcentration;
+impl NoxSensorCorrectedConcentration { pub fn new() -> Self { NoxSensorCorrectedConcentration } }
+impl Pid for NoxSensorCorrectedConcentration {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<u16>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa1 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Concentration corrigée des capteurs NOx, capteurs 1 et 2 des banques 1 et 2" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("ppm") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_nox_sensors(input)
+    }
+}
+
+pub struct CylinderFuelRate;
+impl CylinderFuelRate { pub fn new() -> Self { CylinderFuelRate } }
+impl Pid for CylinderFuelRate {
+    type Input  = u16;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa2 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x02) }
+    fn description(&self) -> &'static str { "Débit de carburant par cylindre" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(2047.96875) }
+    fn unit(&self) -> Option<&'static str> { Some("mg/stroke") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_cylinder_fuel_rate(input)
+    }
+}
+
+pub struct EvapSystemVaporPressureSensors;
+impl EvapSystemVaporPressureSensors { pub fn new() -> Self { EvapSystemVaporPressureSensors } }
+impl Pid for EvapSystemVaporPressureSensors {
+    type Input  = Vec<u8>;
+    type Output = Vec<Option<i16>>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa3 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x09) }
+    fn description(&self) -> &'static str { "Pression de vapeur du système d'évaporation et de purge, capteurs A à D" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("Pa") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_evap_pressure_sensors(input)
+    }
+}
+
+pub struct TransmissionActualGear;
+impl TransmissionActualGear { pub fn new() -> Self { TransmissionActualGear } }
+impl Pid for TransmissionActualGear {
+    type Input  = u32;
+    type Output = TransmissionGear;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa4 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Rapport de transmission engagé et démultiplication" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("(gear, ratio)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_transmission_gear(input)
+    }
+}
+
+pub struct CommandedDieselExhaustFluidDosing;
+impl CommandedDieselExhaustFluidDosing { pub fn new() -> Self { CommandedDieselExhaustFluidDosing } }
+impl Pid for CommandedDieselExhaustFluidDosing {
+    type Input  = u32;
+    type Output = Option<f64>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa5 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Dosage commandé de fluide d'échappement diesel (DEF)" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { Some("%") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_diesel_exhaust_fluid_dosing(input)
+    }
+}
+
+pub struct Odometer;
+impl Odometer { pub fn new() -> Self { Odometer } }
+impl Pid for Odometer {
+    type Input  = u32;
+    type Output = f64;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa6 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Odomètre" }
+    fn min(&self)  -> Option<Self::Output>  { Some(0.0) }
+    fn max(&self)  -> Option<Self::Output>  { Some(429496729.5) }
+    fn unit(&self) -> Option<&'static str> { Some("km") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_odometer(input)
+    }
+}
+
+pub struct NoxSensorConcentration34;
+impl NoxSensorConcentration34 { pub fn new() -> Self { NoxSensorConcentration34 } }
+impl Pid for NoxSensorConcentration34 {
+    type Input  = u32;
+    type Output = (u16, u16);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa7 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Concentration des capteurs NOx 3 et 4" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0, 0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((65535, 65535)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ppm, ppm)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_word_pair(input)
+    }
+}
+
+pub struct NoxSensorCorrectedConcentration34;
+impl NoxSensorCorrectedConcentration34 { pub fn new() -> Self { NoxSensorCorrectedConcentration34 } }
+impl Pid for NoxSensorCorrectedConcentration34 {
+    type Input  = u32;
+    type Output = (u16, u16);
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa8 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Concentration corrigée des capteurs NOx 3 et 4" }
+    fn min(&self)  -> Option<Self::Output>  { Some((0, 0)) }
+    fn max(&self)  -> Option<Self::Output>  { Some((65535, 65535)) }
+    fn unit(&self) -> Option<&'static str> { Some("(ppm, ppm)") }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_word_pair(input)
+    }
+}
+
+pub struct AbsDisableSwitchState;
+impl AbsDisableSwitchState { pub fn new() -> Self { AbsDisableSwitchState } }
+impl Pid for AbsDisableSwitchState {
+    type Input  = u32;
+    type Output = Option<bool>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xa9 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "État de l'interrupteur de désactivation de l'ABS" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_abs_disable_switch(input)
+    }
+}
+
+pub struct AvailablePidsE0;
+impl AvailablePidsE0 { pub fn new() -> Self { AvailablePidsE0 } }
+impl Pid for AvailablePidsE0 {
+    type Input  = u32;
+    type Output = Vec<PidLen>;
+    fn mode_number(&self) -> ModLen { 0x01 }
+    fn pid_number(&self)  -> PidLen { 0xc0 }
+    fn result_size(&self) -> ResultSize { ResultSize::Value(0x04) }
+    fn description(&self) -> &'static str { "Pids supportés de 0xc1 à 0xe0" }
+    fn min(&self)  -> Option<Self::Output>  { None }
+    fn max(&self)  -> Option<Self::Output>  { None }
+    fn unit(&self) -> Option<&'static str> { None }
+    fn interpret_result(&self, input: Self::Input) -> Self::Output {
+        decode_available_pids(input, 6)
+    }
+}
+
+// Position des capteurs d'oxygène
+impl OxygenSensorPid for OxygenSensor1 { fn sensor_index(&self) -> u8 { 1 } }
+impl OxygenSensorPid for OxygenSensor2 { fn sensor_index(&self) -> u8 { 2 } }
+impl OxygenSensorPid for OxygenSensor3 { fn sensor_index(&self) -> u8 { 3 } }
+impl OxygenSensorPid for OxygenSensor4 { fn sensor_index(&self) -> u8 { 4 } }
+impl OxygenSensorPid for OxygenSensor5 { fn sensor_index(&self) -> u8 { 5 } }
+impl OxygenSensorPid for OxygenSensor6 { fn sensor_index(&self) -> u8 { 6 } }
+impl OxygenSensorPid for OxygenSensor7 { fn sensor_index(&self) -> u8 { 7 } }
+impl OxygenSensorPid for OxygenSensor8 { fn sensor_index(&self) -> u8 { 8 } }
+impl OxygenSensorPid for OxygenSensorLambda1 { fn sensor_index(&self) -> u8 { 1 } }
+impl OxygenSensorPid for OxygenSensorLambda2 { fn sensor_index(&self) -> u8 { 2 } }
+impl OxygenSensorPid for OxygenSensorLambda3 { fn sensor_index(&self) -> u8 { 3 } }
+impl OxygenSensorPid for OxygenSensorLambda4 { fn sensor_index(&self) -> u8 { 4 } }
+impl OxygenSensorPid for OxygenSensorLambda5 { fn sensor_index(&self) -> u8 { 5 } }
+impl OxygenSensorPid for OxygenSensorLambda6 { fn sensor_index(&self) -> u8 { 6 } }
+impl OxygenSensorPid for OxygenSensorLambda7 { fn sensor_index(&self) -> u8 { 7 } }
+impl OxygenSensorPid for OxygenSensorLambda8 { fn sensor_index(&self) -> u8 { 8 } }
+impl OxygenSensorPid for OxygenSensorCurrent1 { fn sensor_index(&self) -> u8 { 1 } }
+impl OxygenSensorPid for OxygenSensorCurrent2 { fn sensor_index(&self) -> u8 { 2 } }
+impl OxygenSensorPid for OxygenSensorCurrent3 { fn sensor_index(&self) -> u8 { 3 } }
+impl OxygenSensorPid for OxygenSensorCurrent4 { fn sensor_index(&self) -> u8 { 4 } }
+impl OxygenSensorPid for OxygenSensorCurrent5 { fn sensor_index(&self) -> u8 { 5 } }
+impl OxygenSensorPid for OxygenSensorCurrent6 { fn sensor_index(&self) -> u8 { 6 } }
+impl OxygenSensorPid for OxygenSensorCurrent7 { fn sensor_index(&self) -> u8 { 7 } }
+impl OxygenSensorPid for OxygenSensorCurrent8 { fn sensor_index(&self) -> u8 { 8 } }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_checks_header_and_length() {
+        let rpm : EngineSpeed = EngineSpeed::new();
+        assert_eq!(rpm.request(), vec![0x01, 0x0c]);
+        assert_eq!(rpm.decode(&[0x41, 0x0c, 0x1a, 0xf8]), Ok(1726.0));
+        assert_eq!(rpm.decode(&[]), Err(PidError::Empty));
+        assert_eq!(rpm.decode(&[0x42, 0x0c, 0x1a, 0xf8]), Err(PidError::ModeMismatch { expected: 0x41, received: 0x42 }));
+        assert_eq!(rpm.decode(&[0x41, 0x0d, 0x32]), Err(PidError::PidMismatch { expected: 0x0c, received: 0x0d }));
+        assert_eq!(rpm.decode(&[0x41]), Err(PidError::TooShort { expected: 2, received: 1 }));
+        assert_eq!(rpm.decode(&[0x41, 0x0c, 0x1a]), Err(PidError::TooShort { expected: 2, received: 1 }));
+        assert_eq!(rpm.decode(&[0x41, 0x0c, 0x1a, 0xf8, 0x00]), Err(PidError::TooLong { expected: 2, received: 3 }));
+    }
+
+    #[test]
+    fn negative_responses_carry_their_code() {
+        assert_eq!(VehicleSpeed::new().decode(&[0x7f, 0x01, 0x12]), Err(PidError::NegativeResponse { mode: 0x01, code: 0x12 }));
+        assert_eq!(VehicleSpeed::new().decode(&[0x7f]), Err(PidError::NegativeResponse { mode: 0x01, code: 0x00 }));
+        for code in [0x10, 0x11, 0x12, 0x13, 0x21, 0x22, 0x24, 0x31, 0x33, 0x78, 0x7e].iter() {
+            assert_eq!(NegativeResponseCode::from_code(*code).code(), *code);
+        }
+        assert_eq!(NegativeResponseCode::from_code(0x78), NegativeResponseCode::ResponsePending);
+        assert_eq!(NegativeResponseCode::from_code(0x7e), NegativeResponseCode::Other(0x7e));
+    }
+
+    #[test]
+    fn freeze_frames_reuse_mode01_decoders() {
+        let frozen : FreezeFrame<EngineSpeed> = FreezeFrame::new(EngineSpeed::new(), 0);
+        assert_eq!(frozen.request(), vec![0x02, 0x0c, 0x00]);
+        assert_eq!(frozen.decode(&[0x42, 0x0c, 0x00, 0x1a, 0xf8]), Ok(1726.0));
+        assert_eq!(frozen.decode(&[0x42, 0x0c, 0x01, 0x1a, 0xf8]), Err(PidError::PidMismatch { expected: 0x00, received: 0x01 }));
+        assert_eq!(frozen.decode(&[0x41, 0x0c, 0x1a, 0xf8]), Err(PidError::ModeMismatch { expected: 0x42, received: 0x41 }));
+
+        assert_eq!(freeze_frame_to_mode01(&[0x42, 0x0d, 0x01, 0x32]), Ok((0x01, vec![0x41, 0x0d, 0x32])));
+        assert_eq!(freeze_frame_to_mode01(&[0x42, 0x0d]), Err(PidError::TooShort { expected: 3, received: 2 }));
+        assert_eq!(freeze_frame_to_mode01(&[0x7f, 0x02, 0x31]), Err(PidError::NegativeResponse { mode: 0x02, code: 0x31 }));
+    }
+
+    #[test]
+    fn mode01_pids_30_to_5f_scalings() {
+        assert_eq!(WarmUpsSinceCodesCleared::new().decode(&[0x41, 0x30, 0x05]), Ok(5));
+        assert_eq!(DistanceSinceCodesCleared::new().decode(&[0x41, 0x31, 0x01, 0x00]), Ok(256));
+        assert_eq!(EvapSystemVaporPressure::new().decode(&[0x41, 0x32, 0x00, 0x08]), Ok(2.0));
+        assert_eq!(EvapSystemVaporPressure::new().decode(&[0x41, 0x32, 0xff, 0xfc]), Ok(-1.0));
+        assert_eq!(AbsoluteBarometricPressure::new().decode(&[0x41, 0x33, 0x65]), Ok(101));
+        assert_eq!(OxygenSensorCurrent1::new().decode(&[0x41, 0x34, 0x80, 0x00, 0x80, 0x00]), Ok((1.0, 0.0)));
+        assert_eq!(OxygenSensorCurrent8::new().decode(&[0x41, 0x3b, 0x40, 0x00, 0x7f, 0x00]), Ok((0.5, -1.0)));
+        assert_eq!(CatalystTemperatureBank1Sensor1::new().decode(&[0x41, 0x3c, 0x11, 0x94]), Ok(410.0));
+        assert_eq!(CatalystTemperatureBank2Sensor2::new().decode(&[0x41, 0x3f, 0x00, 0x00]), Ok(-40.0));
+        assert_eq!(ControlModuleVoltage::new().decode(&[0x41, 0x42, 0x36, 0xb0]), Ok(14.0));
+        assert_eq!(AbsoluteLoadValue::new().decode(&[0x41, 0x43, 0x00, 0xff]), Ok(100.0));
+        assert_eq!(CommandedAirFuelEquivalenceRatio::new().decode(&[0x41, 0x44, 0x80, 0x00]), Ok(1.0));
+        assert_eq!(AmbientAirTemperature::new().decode(&[0x41, 0x46, 0x3c]), Ok(20));
+        assert_eq!(TimeRunWithMIL::new().decode(&[0x41, 0x4d, 0x01, 0x2c]), Ok(300));
+        assert_eq!(TimeSinceCodesCleared::new().decode(&[0x41, 0x4e, 0xff, 0xff]), Ok(65535));
+        assert_eq!(MaximumAirFlowRate::new().decode(&[0x41, 0x50, 0x0a, 0x00, 0x00, 0x00]), Ok(100));
+        assert_eq!(AbsoluteEvapSystemVaporPressure::new().decode(&[0x41, 0x53, 0x4e, 0x20]), Ok(100.0));
+        assert_eq!(EvapSystemVaporPressureWide::new().decode(&[0x41, 0x54, 0x80, 0x00]), Ok(-32768));
+        assert_eq!(EvapSystemVaporPressureWide::new().decode(&[0x41, 0x54, 0x01, 0x00]), Ok(256));
+        assert_eq!(ShortTermSecondaryOxygenTrimBanks13::new().decode(&[0x41, 0x55, 0x80, 0x00]), Ok((0.0, -100.0)));
+        assert_eq!(FuelRailAbsolutePressure::new().decode(&[0x41, 0x59, 0x01, 0x00]), Ok(2560));
+        assert_eq!(EngineOilTemperature::new().decode(&[0x41, 0x5c, 0x82]), Ok(90));
+        assert_eq!(FuelInjectionTiming::new().decode(&[0x41, 0x5d, 0x69, 0x00]), Ok(0.0));
+        assert_eq!(FuelInjectionTiming::new().decode(&[0x41, 0x5d, 0x00, 0x00]), Ok(-210.0));
+        assert_eq!(EngineFuelRate::new().decode(&[0x41, 0x5e, 0x00, 0x64]), Ok(5.0));
+
+        let maximum : MaximumValues = MaximumSensorValues::new().decode(&[0x41, 0x4f, 0x02, 0x05, 0x0a, 0x32]).unwrap();
+        assert_eq!((maximum.equivalence_ratio, maximum.oxygen_sensor_voltage, maximum.oxygen_sensor_current, maximum.intake_manifold_pressure),
+            (2, 5, 10, 500));
+        assert_eq!(format!("{:?}", FuelTypeUsed::new().decode(&[0x41, 0x51, 0x04]).unwrap()), "Diesel");
+        assert_eq!(format!("{:?}", EmissionRequirementsForThisVehicle::new().decode(&[0x41, 0x5f, 0x0e]).unwrap()), "HeavyDutyEuroIV");
+        assert_eq!(format!("{:?}", EmissionRequirementsForThisVehicle::new().decode(&[0x41, 0x5f, 0x01]).unwrap()), "Value(1)");
+    }
+
+    #[test]
+    fn turbocharger_rpm_is_10_rpm_per_bit() {
+        assert_eq!(TurbochargerRpm::new().decode(&[0x41, 0x74, 0x03, 0x30, 0x39, 0xff, 0xff]), Ok((Some(123_450), Some(655_350))));
+        assert_eq!(TurbochargerRpm::new().decode(&[0x41, 0x74, 0x02, 0x30, 0x39, 0x00, 0x01]), Ok((None, Some(10))));
+        assert_eq!(TurbochargerRpm::new().decode(&[0x41, 0x74, 0x03, 0x30, 0x39]), Err(PidError::TooShort { expected: 5, received: 3 }));
+    }
+
+    #[test]
+    fn torque_and_auxiliary_pids_60_to_65() {
+        assert_eq!(DriverDemandEnginePercentTorque::new().decode(&[0x41, 0x61, 0x00]), Ok(-125));
+        assert_eq!(ActualEnginePercentTorque::new().decode(&[0x41, 0x62, 0xff]), Ok(130));
+        assert_eq!(EngineFrictionPercentTorque::new().decode(&[0x41, 0x8e, 0x7d]), Ok(0));
+        assert_eq!(EnginePercentTorqueData::new().decode(&[0x41, 0x64, 0x7d, 0x00, 0xff, 0x80, 0x7d]), Ok(vec![0, -125, 130, 3, 0]));
+
+        let aux = AuxiliaryInputOutputSupported::new().decode(&[0x41, 0x65, 0x05, 0x01]).unwrap();
+        assert_eq!(aux.power_take_off_active, Some(true));
+        assert_eq!(aux.automatic_transmission_in_gear, None);
+        assert_eq!(aux.manual_transmission_in_gear, Some(false));
+        assert_eq!(aux.glow_plug_lamp_on, None);
+    }
+
+    #[test]
+    fn sensor_pairs_follow_their_support_bits() {
+        assert_eq!(MassAirFlowSensors::new().decode(&[0x41, 0x66, 0x01, 0x01, 0x40, 0x00, 0x20]), Ok((Some(10.0), None)));
+        assert_eq!(EngineCoolantTemperatureSensors::new().decode(&[0x41, 0x67, 0x03, 0x00, 0x5a]), Ok((Some(-40), Some(50))));
+        assert_eq!(TurbochargerCompressorInletPressure::new().decode(&[0x41, 0x6f, 0x02, 0x10, 0x65]), Ok((None, Some(101))));
+        assert_eq!(ExhaustPressure::new().decode(&[0x41, 0x73, 0x01, 0x03, 0xe8, 0xff, 0xff]), Ok((Some(10.0), None)));
+        assert_eq!(ParticulateMatterSensor::new().decode(&[0x41, 0x86, 0x03, 0x00, 0x50, 0x00, 0xa0]), Ok((Some(1.0), Some(2.0))));
+        assert_eq!(IntakeManifoldAbsolutePressureSensors::new().decode(&[0x41, 0x87, 0x02, 0x00, 0x00, 0x0c, 0x80]), Ok((None, Some(100.0))));
+        assert_eq!(ManifoldSurfaceTemperature::new().decode(&[0x41, 0x84, 0x28]), Ok(0));
+    }
+
+    #[test]
+    fn egr_and_fuel_pressure_scalings() {
+        // Rapport commandé A et erreur A seulement ; l'erreur est centrée sur 0x80
+        assert_eq!(CommandedEGRAndEGRError::new().decode(&[0x41, 0x69, 0x05, 0x00, 0x33, 0x00, 0x00, 0x00, 0x00]),
+            Ok(vec![Some(0.0), None, Some(-100.0), None, None, None]));
+        // Bit 4 : plage étendue de 4 °C par bit pour le capteur 1
+        assert_eq!(ExhaustGasRecirculationTemperature::new().decode(&[0x41, 0x6b, 0x13, 0x32, 0x32, 0x32, 0x32]),
+            Ok(vec![Some(160), Some(10), None, None]));
+
+        let fuel = FuelPressureControlSystem::new().decode(&[0x41, 0x6d, 0x07, 0x03, 0xe8, 0x03, 0xe7, 0x5a, 0x00, 0x00, 0x00, 0x00, 0x00]).unwrap();
+        assert_eq!(fuel.commanded_rail_pressure_a, Some(10_000));
+        assert_eq!(fuel.rail_pressure_a, Some(9_990));
+        assert_eq!(fuel.rail_temperature_a, Some(50));
+        assert_eq!(fuel.commanded_rail_pressure_b, None);
+        assert_eq!(fuel.rail_temperature_b, None);
+
+        assert_eq!(InjectionPressureControlSystem::new().decode(&[0x41, 0x6e, 0x02, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04]),
+            Ok(vec![None, Some(20), None, None]));
+    }
+
+    #[test]
+    fn boost_and_turbo_control_status() {
+        let boost = BoostPressureControlSystem::new().decode(&[0x41, 0x70, 0x31, 0x01, 0x40, 0x01, 0x40, 0x00, 0x00, 0x00, 0x00, 0x09]).unwrap();
+        assert_eq!(boost.commanded_pressure_a, Some(10.0));
+        assert_eq!(boost.pressure_a, None);
+        assert!(matches!(boost.status_a, Some(ControlLoopStatus::OpenLoop)));
+        assert!(matches!(boost.status_b, Some(ControlLoopStatus::ClosedLoop)));
+
+        let vgt = VariableGeometryTurboControlSystem::new().decode(&[0x41, 0x71, 0x13, 0x00, 0x00, 0x00, 0x00, 0x03]).unwrap();
+        assert_eq!(vgt.commanded_position_a, Some(0.0));
+        assert_eq!(vgt.position_a, Some(0.0));
+        assert_eq!(vgt.commanded_position_b, None);
+        assert!(matches!(vgt.status_a, Some(ControlLoopStatus::Fault)));
+        assert!(vgt.status_b.is_none());
+
+        let turbo = TurbochargerATemperature::new().decode(&[0x41, 0x75, 0x0f, 0x5a, 0x28, 0x11, 0x94, 0x01, 0x90]).unwrap();
+        assert_eq!(turbo.compressor_inlet, Some(50));
+        assert_eq!(turbo.compressor_outlet, Some(0));
+        assert_eq!(turbo.turbine_inlet, Some(410.0));
+        assert_eq!(turbo.turbine_outlet, Some(0.0));
+    }
+
+    #[test]
+    fn exhaust_and_particulate_filter_scalings() {
+        assert_eq!(ExhaustGasTemperatureBank1::new().decode(&[0x41, 0x78, 0x05, 0x11, 0x94, 0x00, 0x00, 0x01, 0x90, 0x00, 0x00]),
+            Ok(vec![Some(410.0), None, Some(0.0), None]));
+        // Pression différentielle signée : 0xff06 = -250, soit -2,5 kPa
+        assert_eq!(DieselParticulateFilter1::new().decode(&[0x41, 0x7a, 0x07, 0xff, 0x06, 0x03, 0xe8, 0x00, 0x64]),
+            Ok(vec![Some(-2.5), Some(10.0), Some(1.0)]));
+
+        let nte = NoxNteControlAreaStatus::new().decode(&[0x41, 0x7d, 0x09]).unwrap();
+        assert!(nte.inside_control_area);
+        assert!(!nte.outside_control_area);
+        assert!(!nte.inside_carve_out);
+        assert!(nte.deficiency_active);
+    }
+
+    #[test]
+    fn run_times_and_nox_pids() {
+        assert_eq!(EngineRunTime::new().decode(&[0x41, 0x7f, 0x05, 0x00, 0x00, 0x0e, 0x10, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01]),
+            Ok(vec![Some(3600), None, Some(1)]));
+        let mut aecd = vec![0x41, 0x89, 0x10];
+        aecd.extend_from_slice(&[0x00; 16]);
+        aecd.extend_from_slice(&[0x00, 0x00, 0x00, 0x07]);
+        assert_eq!(EngineRunTimeForAecd3::new().decode(&aecd), Ok(vec![None, None, None, None, Some(7)]));
+        aecd[1] = 0x8a;
+        assert_eq!(EngineRunTimeForAecd4::new().decode(&aecd), Ok(vec![None, None, None, None, Some(7)]));
+
+        assert_eq!(NoxSensor::new().decode(&[0x41, 0x83, 0x03, 0x00, 0x64, 0x00, 0xc8, 0x00, 0x00, 0x00, 0x00]),
+            Ok(vec![Some(100), Some(200), None, None]));
+
+        let reagent = NoxReagentSystemStatus::new().decode(&[0x41, 0x85, 0x0c, 0x00, 0x01, 0x00, 0x01, 0xff, 0x00, 0x00, 0x00, 0x3c]).unwrap();
+        assert_eq!(reagent.average_consumption, None);
+        assert_eq!(reagent.average_demanded_consumption, None);
+        assert_eq!(reagent.tank_level, Some(decode_percent(0xff)));
+        assert_eq!(reagent.warning_timer, Some(60));
+    }
+
+    #[test]
+    fn support_byte_pids_88_to_8f() {
+        let scr = ScrInducementSystem::new().decode(&[0x41, 0x88, 0x81, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]).unwrap();
+        assert_eq!(scr.supported, vec![0, 7]);
+        assert_eq!(scr.data.len(), 12);
+
+        let aftertreatment = DieselAftertreatment::new().decode(&[0x41, 0x8b, 0x01, 0x02, 0, 0, 0, 0, 0]).unwrap();
+        assert_eq!(aftertreatment.supported, vec![0]);
+        assert_eq!(aftertreatment.data, vec![0x02, 0, 0, 0, 0, 0]);
+
+        assert_eq!(OxygenSensorWideRange::new().decode(&[0x41, 0x8c, 0x03]).unwrap_err(), PidError::TooShort { expected: 17, received: 1 });
+        assert_eq!(ParticulateMatterSensorOutput::new().decode(&[0x41, 0x8f, 0x03, 0, 0, 0, 0, 0, 0]).unwrap().supported, vec![0, 1]);
+    }
+
+    #[test]
+    fn support_bitmaps_from_0x90() {
+        assert_eq!(FuelSystemControl::new().decode(&[0x41, 0x92, 0x03, 0x01]),
+            Ok(vec![Some(true), Some(false), None, None, None, None, None, None]));
+
+        // Trois octets de support : le bit 7 du troisième octet est l'indice 23
+        let counters = WwhObdCountersSupport::new().decode(&[0x41, 0x93, 0x01, 0x00, 0x80]).unwrap();
+        assert_eq!(counters.supported, vec![0, 23]);
+        assert!(counters.data.is_empty());
+    }
+
+    #[test]
+    fn hybrid_and_diesel_exhaust_fluid_data() {
+        let hybrid = HybridVehicleSystemData::new().decode(&[0x41, 0x9a, 0x07, 0x02, 0x4b, 0x00, 0xff, 0x9c]).unwrap();
+        assert_eq!(hybrid.charging_state, Some(2));
+        assert_eq!(hybrid.battery_voltage, Some(300.0));
+        assert_eq!(hybrid.battery_current, Some(-10.0));
+
+        let fluid = DieselExhaustFluidSensorData::new().decode(&[0x41, 0x9b, 0x03, 0x80, 0x3c, 0xff]).unwrap();
+        assert_eq!(fluid.concentration, Some(32.0));
+        assert_eq!(fluid.temperature, Some(20));
+        assert_eq!(fluid.tank_level, None);
+
+        assert_eq!(CommandedDieselExhaustFluidDosing::new().decode(&[0x41, 0xa5, 0x01, 0x64, 0x00, 0x00]), Ok(Some(50.0)));
+        assert_eq!(CommandedDieselExhaustFluidDosing::new().decode(&[0x41, 0xa5, 0x00, 0x64, 0x00, 0x00]), Ok(None));
+    }
+
+    #[test]
+    fn fuel_flow_and_transmission_scalings() {
+        assert_eq!(EngineFuelRateMass::new().decode(&[0x41, 0x9d, 0x01, 0xf4, 0x00, 0x32]), Ok((10.0, 1.0)));
+        assert_eq!(EngineExhaustFlowRate::new().decode(&[0x41, 0x9e, 0x01, 0xf4]), Ok(100.0));
+        assert_eq!(CylinderFuelRate::new().decode(&[0x41, 0xa2, 0x01, 0x40]), Ok(10.0));
+        assert_eq!(Odometer::new().decode(&[0x41, 0xa6, 0x00, 0x00, 0x27, 0x10]), Ok(1000.0));
+
+        let gear = TransmissionActualGear::new().decode(&[0x41, 0xa4, 0x02, 0x30, 0x0b, 0xb8]).unwrap();
+        assert_eq!(gear.gear, Some(3));
+        assert_eq!(gear.ratio, Some(3.0));
+        assert_eq!(TransmissionActualGear::new().decode(&[0x41, 0xa4, 0x00, 0x30, 0x0b, 0xb8]).unwrap().gear, None);
+    }
+
+    #[test]
+    fn evap_sensors_are_signed() {
+        assert_eq!(EvapSystemVaporPressureSensors::new().decode(&[0x41, 0xa3, 0x05, 0xff, 0x38, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]),
+            Ok(vec![Some(-200), None, Some(256), None]));
+    }
+
+    #[test]
+    fn nox_concentrations_and_abs_switch() {
+        assert_eq!(NoxSensorCorrectedConcentration::new().decode(&[0x41, 0xa1, 0x01, 0x00, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            Ok(vec![Some(100), None, None, None]));
+        assert_eq!(NoxSensorConcentration34::new().decode(&[0x41, 0xa7, 0x00, 0x64, 0x00, 0xc8]), Ok((100, 200)));
+        assert_eq!(NoxSensorCorrectedConcentration34::new().decode(&[0x41, 0xa8, 0x00, 0x64, 0x00, 0xc8]), Ok((100, 200)));
+
+        assert_eq!(AbsDisableSwitchState::new().decode(&[0x41, 0xa9, 0x01, 0x01, 0x00, 0x00]), Ok(Some(true)));
+        assert_eq!(AbsDisableSwitchState::new().decode(&[0x41, 0xa9, 0x01, 0x00, 0x00, 0x00]), Ok(Some(false)));
+        assert_eq!(AbsDisableSwitchState::new().decode(&[0x41, 0xa9, 0x00, 0x01, 0x00, 0x00]), Ok(None));
+    }
+}
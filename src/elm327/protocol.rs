@@ -0,0 +1,231 @@
+// Uses
+use std::convert::TryFrom;
+use std::fmt;
+
+// Enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ObdProtocol {
+    //
+    // Protocoles sélectionnables par ATSP, numérotés comme l'elm327 (0 à C)
+    //
+    Automatic,
+    SaeJ1850Pwm,
+    SaeJ1850Vpw,
+    Iso9141,
+    Iso14230Kwp5Baud,
+    Iso14230KwpFast,
+    Iso15765Can11Bit500,
+    Iso15765Can29Bit500,
+    Iso15765Can11Bit250,
+    Iso15765Can29Bit250,
+    SaeJ1939,
+    UserCan1,
+    UserCan2
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameChecksum {
+    //
+    // Octet de contrôle final des protocoles hors CAN
+    // :variant Crc8: CRC-8 SAE J1850
+    // :variant Sum:  Somme des octets modulo 256 (ISO 9141-2, ISO 14230-4)
+    //
+    Crc8,
+    Sum
+}
+
+// Structs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownProtocol(pub u8);
+
+impl fmt::Display for UnknownProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Numéro de protocole inconnu : {:X}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownProtocol {}
+
+impl ObdProtocol {
+    pub fn number(&self) -> u8 {
+        //
+        // Chiffre hexadécimal utilisé par ATSP, ATTP et ATDPN
+        //
+        match self {
+            ObdProtocol::Automatic           => 0x00,
+            ObdProtocol::SaeJ1850Pwm         => 0x01,
+            ObdProtocol::SaeJ1850Vpw         => 0x02,
+            ObdProtocol::Iso9141             => 0x03,
+            ObdProtocol::Iso14230Kwp5Baud    => 0x04,
+            ObdProtocol::Iso14230KwpFast     => 0x05,
+            ObdProtocol::Iso15765Can11Bit500 => 0x06,
+            ObdProtocol::Iso15765Can29Bit500 => 0x07,
+            ObdProtocol::Iso15765Can11Bit250 => 0x08,
+            ObdProtocol::Iso15765Can29Bit250 => 0x09,
+            ObdProtocol::SaeJ1939            => 0x0a,
+            ObdProtocol::UserCan1            => 0x0b,
+            ObdProtocol::UserCan2            => 0x0c
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        //
+        // Libellé retourné par ATDP
+        //
+        match self {
+            ObdProtocol::Automatic           => "AUTO",
+            ObdProtocol::SaeJ1850Pwm         => "SAE J1850 PWM",
+            ObdProtocol::SaeJ1850Vpw         => "SAE J1850 VPW",
+            ObdProtocol::Iso9141             => "ISO 9141-2",
+            ObdProtocol::Iso14230Kwp5Baud    => "ISO 14230-4 (KWP 5BAUD)",
+            ObdProtocol::Iso14230KwpFast     => "ISO 14230-4 (KWP FAST)",
+            ObdProtocol::Iso15765Can11Bit500 => "ISO 15765-4 (CAN 11/500)",
+            ObdProtocol::Iso15765Can29Bit500 => "ISO 15765-4 (CAN 29/500)",
+            ObdProtocol::Iso15765Can11Bit250 => "ISO 15765-4 (CAN 11/250)",
+            ObdProtocol::Iso15765Can29Bit250 => "ISO 15765-4 (CAN 29/250)",
+            ObdProtocol::SaeJ1939            => "SAE J1939 (CAN 29/250)",
+            ObdProtocol::UserCan1            => "USER1 (CAN 11/125)",
+            ObdProtocol::UserCan2            => "USER2 (CAN 11/50)"
+        }
+    }
+
+    pub fn is_can(&self) -> bool {
+        matches!(self, ObdProtocol::Iso15765Can11Bit500 | ObdProtocol::Iso15765Can29Bit500
+            | ObdProtocol::Iso15765Can11Bit250 | ObdProtocol::Iso15765Can29Bit250
+            | ObdProtocol::SaeJ1939 | ObdProtocol::UserCan1 | ObdProtocol::UserCan2)
+    }
+
+    pub fn is_extended_can(&self) -> bool {
+        //
+        // Identifiants CAN sur 29 bits, les protocoles utilisateur B et C étant en 11 bits par défaut
+        //
+        matches!(self, ObdProtocol::Iso15765Can29Bit500 | ObdProtocol::Iso15765Can29Bit250 | ObdProtocol::SaeJ1939)
+    }
+
+    pub fn uses_isotp(&self) -> bool {
+        //
+        // Trames CAN précédées d'un octet PCI (ISO 15765-2), SAE J1939 transportant ses données sans
+        //
+        self.is_can() && *self != ObdProtocol::SaeJ1939
+    }
+
+    pub fn header_len(&self) -> Option<usize> {
+        //
+        // Longueur de l'entête affiché par ATH1, en chiffres hexadécimaux : identifiant CAN 11 bits (3) ou 29 bits (8),
+        // entête priorité / cible / source (6) suivi en ISO 14230-4 d'un octet de longueur quand le format n'en porte pas
+        //
+        match self {
+            ObdProtocol::Automatic      => None,
+            _ if self.is_extended_can() => Some(8),
+            _ if self.is_can()          => Some(3),
+            _                           => Some(6)
+        }
+    }
+
+    pub fn max_data_bytes(&self) -> Option<usize> {
+        //
+        // Octets de données par trame : 7 hors CAN (entête et somme de contrôle exclus),
+        // 7 sur ISO 15765-4 (l'octet PCI occupant le premier des 8), 8 en J1939
+        //
+        match self {
+            ObdProtocol::Automatic => None,
+            ObdProtocol::SaeJ1939  => Some(8),
+            _                      => Some(7)
+        }
+    }
+
+    pub fn checksum(&self) -> Option<FrameChecksum> {
+        //
+        // Octet de contrôle affiché en fin de trame avec ATH1
+        //
+        match self {
+            ObdProtocol::SaeJ1850Pwm | ObdProtocol::SaeJ1850Vpw => Some(FrameChecksum::Crc8),
+            ObdProtocol::Iso9141 | ObdProtocol::Iso14230Kwp5Baud | ObdProtocol::Iso14230KwpFast => Some(FrameChecksum::Sum),
+            _ => None
+        }
+    }
+
+    pub fn allows_multi_pid(&self) -> bool {
+        //
+        // Seul l'ISO 15765-4 autorise jusqu'à 6 pids dans une même requête du mode 0x01
+        //
+        matches!(self, ObdProtocol::Iso15765Can11Bit500 | ObdProtocol::Iso15765Can29Bit500
+            | ObdProtocol::Iso15765Can11Bit250 | ObdProtocol::Iso15765Can29Bit250)
+    }
+}
+
+impl TryFrom<u8> for ObdProtocol {
+    type Error = UnknownProtocol;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(ObdProtocol::Automatic),
+            0x01 => Ok(ObdProtocol::SaeJ1850Pwm),
+            0x02 => Ok(ObdProtocol::SaeJ1850Vpw),
+            0x03 => Ok(ObdProtocol::Iso9141),
+            0x04 => Ok(ObdProtocol::Iso14230Kwp5Baud),
+            0x05 => Ok(ObdProtocol::Iso14230KwpFast),
+            0x06 => Ok(ObdProtocol::Iso15765Can11Bit500),
+            0x07 => Ok(ObdProtocol::Iso15765Can29Bit500),
+            0x08 => Ok(ObdProtocol::Iso15765Can11Bit250),
+            0x09 => Ok(ObdProtocol::Iso15765Can29Bit250),
+            0x0a => Ok(ObdProtocol::SaeJ1939),
+            0x0b => Ok(ObdProtocol::UserCan1),
+            0x0c => Ok(ObdProtocol::UserCan2),
+            _    => Err(UnknownProtocol(value))
+        }
+    }
+}
+
+impl fmt::Display for ObdProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elm327::definition::*;
+
+    #[test]
+    fn protocol_digits_round_trip() {
+        for number in 0x00..=0x0c {
+            assert_eq!(ObdProtocol::try_from(number).map(|p| p.number()), Ok(number));
+        }
+        assert_eq!(ObdProtocol::try_from(0x0d), Err(UnknownProtocol(0x0d)));
+        assert_eq!(AtCommand::SetProtocol(ObdProtocol::SaeJ1939).command(), "ATSPA");
+        assert_eq!(AtCommand::TryProtocolAuto(ObdProtocol::Iso14230KwpFast).command(), "ATTPA5");
+        assert_eq!(AtCommand::DescribeProtocolNumber.parse_reply("AB\r\r"),
+            Ok(AtReply::ProtocolNumber { automatic: true, protocol: ObdProtocol::UserCan1 }));
+        assert_eq!(AtCommand::DescribeProtocolNumber.parse_reply("3\r\r"),
+            Ok(AtReply::ProtocolNumber { automatic: false, protocol: ObdProtocol::Iso9141 }));
+        assert!(AtCommand::DescribeProtocolNumber.parse_reply("D\r\r").is_err());
+    }
+
+    #[test]
+    fn automatic_cannot_be_its_own_fallback() {
+        assert_eq!(AtCommand::SetProtocolAuto(ObdProtocol::Automatic).check(), Err(AtError::Invalid("ATSPA0".to_string())));
+        assert_eq!(AtCommand::TryProtocolAuto(ObdProtocol::Automatic).check(), Err(AtError::Invalid("ATTPA0".to_string())));
+        assert_eq!(AtCommand::SetProtocolAuto(ObdProtocol::Iso15765Can11Bit500).check(), Ok(()));
+        assert_eq!(AtCommand::SetProtocol(ObdProtocol::Automatic).check(), Ok(()));
+    }
+
+    #[test]
+    fn capabilities() {
+        assert_eq!(ObdProtocol::Automatic.header_len(), None);
+        assert_eq!(ObdProtocol::Iso15765Can11Bit250.header_len(), Some(3));
+        assert_eq!(ObdProtocol::Iso15765Can29Bit500.header_len(), Some(8));
+        assert_eq!(ObdProtocol::SaeJ1939.header_len(), Some(8));
+        assert_eq!(ObdProtocol::SaeJ1850Vpw.header_len(), Some(6));
+
+        assert_eq!(ObdProtocol::SaeJ1850Pwm.checksum(), Some(FrameChecksum::Crc8));
+        assert_eq!(ObdProtocol::Iso14230Kwp5Baud.checksum(), Some(FrameChecksum::Sum));
+        assert_eq!(ObdProtocol::UserCan2.checksum(), None);
+
+        assert_eq!(ObdProtocol::SaeJ1939.max_data_bytes(), Some(8));
+        assert_eq!(ObdProtocol::Iso9141.max_data_bytes(), Some(7));
+        assert!(ObdProtocol::UserCan1.uses_isotp() && !ObdProtocol::SaeJ1939.uses_isotp() && !ObdProtocol::Iso9141.uses_isotp());
+        assert!(ObdProtocol::Iso15765Can29Bit250.allows_multi_pid());
+        assert!(!ObdProtocol::SaeJ1939.allows_multi_pid() && !ObdProtocol::UserCan1.allows_multi_pid() && !ObdProtocol::SaeJ1850Pwm.allows_multi_pid());
+    }
+}
@@ -0,0 +1,619 @@
+// Uses
+use std::collections::BTreeMap;
+use crate::elm327::decoder::*;
+use crate::elm327::dtc::Dtc;
+use crate::elm327::pids::*;
+use crate::elm327::types::*;
+
+// Enums
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodedValue {
+    //
+    // Valeur décodée d'un pid, indépendante de son type de sortie
+    //
+    Empty,
+    Scalar(f64, Option<&'static str>),
+    Pair(Box<DecodedValue>, Box<DecodedValue>),
+    Label(String),
+    Bitfield(Vec<(&'static str, bool)>),
+    List(Vec<DecodedValue>)
+}
+
+// Structs
+#[derive(Debug, Clone)]
+pub struct PidInfo {
+    pub mode_number: ModLen,
+    pub pid_number:  PidLen,
+    pub result_size: ResultSize,
+    pub description: &'static str,
+    pub unit:        Option<&'static str>
+}
+
+pub struct PidRegistry {
+    //
+    // Ensemble de pids indexés par (mode, pid), décodant vers DecodedValue
+    //
+    pids: BTreeMap<(ModLen, PidLen), Box<dyn DynPid>>
+}
+
+// Conversion des sorties des pids vers DecodedValue
+pub trait IntoDecodedValue {
+    //
+    // Trait convertissant la sortie d'un pid en DecodedValue
+    // :fn into_decoded: Méthode effectuant la conversion, unit étant l'unité annoncée par le pid
+    //
+    fn into_decoded(self, unit: Option<&'static str>) -> DecodedValue;
+}
+
+macro_rules! scalar_into_decoded {
+    ($($t:ty),*) => {
+        $(impl IntoDecodedValue for $t {
+            fn into_decoded(self, unit: Option<&'static str>) -> DecodedValue {
+                DecodedValue::Scalar(self as f64, unit)
+            }
+        })*
+    };
+}
+
+macro_rules! label_into_decoded {
+    ($($t:ty),*) => {
+        $(impl IntoDecodedValue for $t {
+            fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+                DecodedValue::Label(format!("{:?}", self))
+            }
+        })*
+    };
+}
+
+scalar_into_decoded!(u8, u16, u32, i16, usize, f64);
+label_into_decoded!(FuelSystem, AirStatus, ObdStandard, State, FuelType, EmissionRequirements, ControlLoopStatus, bool);
+
+impl IntoDecodedValue for () {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::Empty
+    }
+}
+
+impl IntoDecodedValue for MaximumValues {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            DecodedValue::Scalar(self.equivalence_ratio as f64, Some("ratio")),
+            DecodedValue::Scalar(self.oxygen_sensor_voltage as f64, Some("V")),
+            DecodedValue::Scalar(self.oxygen_sensor_current as f64, Some("mA")),
+            DecodedValue::Scalar(self.intake_manifold_pressure as f64, Some("kPa"))
+        ])
+    }
+}
+
+impl IntoDecodedValue for AuxiliaryInputOutput {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        supported_flags(vec![
+            ("power_take_off_active",          self.power_take_off_active),
+            ("automatic_transmission_in_gear", self.automatic_transmission_in_gear),
+            ("manual_transmission_in_gear",    self.manual_transmission_in_gear),
+            ("glow_plug_lamp_on",              self.glow_plug_lamp_on)
+        ])
+    }
+}
+
+impl IntoDecodedValue for FuelPressureControl {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            self.commanded_rail_pressure_a.into_decoded(Some("kPa")),
+            self.rail_pressure_a.into_decoded(Some("kPa")),
+            self.rail_temperature_a.into_decoded(Some("°C")),
+            self.commanded_rail_pressure_b.into_decoded(Some("kPa")),
+            self.rail_pressure_b.into_decoded(Some("kPa")),
+            self.rail_temperature_b.into_decoded(Some("°C"))
+        ])
+    }
+}
+
+impl IntoDecodedValue for BoostPressureControl {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            self.commanded_pressure_a.into_decoded(Some("kPa")),
+            self.pressure_a.into_decoded(Some("kPa")),
+            self.commanded_pressure_b.into_decoded(Some("kPa")),
+            self.pressure_b.into_decoded(Some("kPa")),
+            self.status_a.into_decoded(None),
+            self.status_b.into_decoded(None)
+        ])
+    }
+}
+
+impl IntoDecodedValue for VariableGeometryTurboControl {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            self.commanded_position_a.into_decoded(Some("%")),
+            self.position_a.into_decoded(Some("%")),
+            self.commanded_position_b.into_decoded(Some("%")),
+            self.position_b.into_decoded(Some("%")),
+            self.status_a.into_decoded(None),
+            self.status_b.into_decoded(None)
+        ])
+    }
+}
+
+impl IntoDecodedValue for TurbochargerTemperature {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            self.compressor_inlet.into_decoded(Some("°C")),
+            self.compressor_outlet.into_decoded(Some("°C")),
+            self.turbine_inlet.into_decoded(Some("°C")),
+            self.turbine_outlet.into_decoded(Some("°C"))
+        ])
+    }
+}
+
+impl IntoDecodedValue for NteStatus {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::Bitfield(vec![
+            ("inside_control_area",  self.inside_control_area),
+            ("outside_control_area", self.outside_control_area),
+            ("inside_carve_out",     self.inside_carve_out),
+            ("deficiency_active",    self.deficiency_active)
+        ])
+    }
+}
+
+impl IntoDecodedValue for NoxReagentSystem {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            self.average_consumption.into_decoded(Some("L/h")),
+            self.average_demanded_consumption.into_decoded(Some("L/h")),
+            self.tank_level.into_decoded(Some("%")),
+            self.warning_timer.into_decoded(Some("seconds"))
+        ])
+    }
+}
+
+impl IntoDecodedValue for OxygenSensorLocation {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::Label(self.to_string())
+    }
+}
+
+impl IntoDecodedValue for MonitorStatus {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            DecodedValue::Bitfield(vec![("mil", self.mil)]),
+            DecodedValue::Scalar(self.dtc_count as f64, None),
+            DecodedValue::Label(format!("{:?}", self.ignition)),
+            DecodedValue::Bitfield(self.monitors().filter(|t| t.available).map(|t| (t.monitor.name(), t.complete)).collect())
+        ])
+    }
+}
+
+impl IntoDecodedValue for DriveCycleMonitorStatus {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            DecodedValue::Label(format!("{:?}", self.ignition)),
+            DecodedValue::Bitfield(self.monitors().filter(|t| t.available).map(|t| (t.monitor.name(), t.complete)).collect())
+        ])
+    }
+}
+
+impl IntoDecodedValue for SupportedData {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::Pair(
+            Box::new(self.supported.into_decoded(None)),
+            Box::new(self.data.into_decoded(None))
+        )
+    }
+}
+
+impl IntoDecodedValue for HybridSystemData {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            self.charging_state.into_decoded(None),
+            self.battery_voltage.into_decoded(Some("V")),
+            self.battery_current.into_decoded(Some("A"))
+        ])
+    }
+}
+
+impl IntoDecodedValue for DieselExhaustFluidData {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(vec![
+            self.concentration.into_decoded(Some("%")),
+            self.temperature.into_decoded(Some("°C")),
+            self.tank_level.into_decoded(Some("%"))
+        ])
+    }
+}
+
+impl IntoDecodedValue for TransmissionGear {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::Pair(
+            Box::new(self.gear.into_decoded(None)),
+            Box::new(self.ratio.into_decoded(Some("ratio")))
+        )
+    }
+}
+
+impl IntoDecodedValue for Dtc {
+    fn into_decoded(self, _: Option<&'static str>) -> DecodedValue {
+        DecodedValue::Label(self.to_string())
+    }
+}
+
+impl<T: IntoDecodedValue> IntoDecodedValue for Option<T> {
+    fn into_decoded(self, unit: Option<&'static str>) -> DecodedValue {
+        match self {
+            Some(value) => value.into_decoded(unit),
+            None        => DecodedValue::Empty
+        }
+    }
+}
+
+impl<A: IntoDecodedValue, B: IntoDecodedValue> IntoDecodedValue for (A, B) {
+    fn into_decoded(self, unit: Option<&'static str>) -> DecodedValue {
+        let (first, second) = split_pair_unit(unit);
+        DecodedValue::Pair(Box::new(self.0.into_decoded(first)), Box::new(self.1.into_decoded(second)))
+    }
+}
+
+impl<T: IntoDecodedValue> IntoDecodedValue for Vec<T> {
+    fn into_decoded(self, unit: Option<&'static str>) -> DecodedValue {
+        DecodedValue::List(self.into_iter().map(|v| v.into_decoded(unit)).collect())
+    }
+}
+
+// Pid sans types associés, utilisable derrière un Box<dyn DynPid>
+pub trait DynPid {
+    //
+    // Trait objet-compatible implémenté par tous les pids dont l'entrée et la sortie sont convertibles
+    // :fn info:          Méthode retournant les métadonnées du pid
+    // :fn request_bytes: Octets de la requête à envoyer au véhicule
+    // :fn decode_value:  Méthode vérifiant puis décodant une réponse brute
+    //
+    fn info(&self) -> PidInfo;
+    fn request_bytes(&self) -> Vec<u8>;
+    fn decode_value(&self, response: &[u8]) -> Result<DecodedValue, PidError>;
+}
+
+impl<P> DynPid for P where P: Pid, P::Input: FromBytes, P::Output: IntoDecodedValue {
+    fn info(&self) -> PidInfo {
+        PidInfo {
+            mode_number: self.mode_number(),
+            pid_number:  self.pid_number(),
+            result_size: self.result_size(),
+            description: self.description(),
+            unit:        self.unit()
+        }
+    }
+
+    fn request_bytes(&self) -> Vec<u8> {
+        self.request()
+    }
+
+    fn decode_value(&self, response: &[u8]) -> Result<DecodedValue, PidError> {
+        Ok(self.decode(response)?.into_decoded(self.unit()))
+    }
+}
+
+impl PidRegistry {
+    pub fn new() -> Self {
+        PidRegistry { pids: BTreeMap::new() }
+    }
+
+    pub fn standard() -> Self {
+        let mut registry : PidRegistry = Self::new();
+        registry.register_mode01();
+        registry
+    }
+
+    pub fn register<P: DynPid + 'static>(&mut self, pid: P) -> Option<Box<dyn DynPid>> {
+        let info : PidInfo = pid.info();
+        self.pids.insert((info.mode_number, info.pid_number), Box::new(pid))
+    }
+
+    pub fn get(&self, mode: ModLen, pid: PidLen) -> Option<&dyn DynPid> {
+        self.pids.get(&(mode, pid)).map(|p| p.as_ref())
+    }
+
+    pub fn contains(&self, mode: ModLen, pid: PidLen) -> bool {
+        self.pids.contains_key(&(mode, pid))
+    }
+
+    pub fn len(&self) -> usize {
+        self.pids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pids.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynPid> {
+        self.pids.values().map(|p| p.as_ref())
+    }
+
+    pub fn mode(&self, mode: ModLen) -> impl Iterator<Item = &dyn DynPid> {
+        self.pids.range((mode, 0)..(mode + 1, 0)).map(|(_, p)| p.as_ref())
+    }
+
+    pub fn decode(&self, mode: ModLen, pid: PidLen, response: &[u8]) -> Result<DecodedValue, PidError> {
+        match self.get(mode, pid) {
+            Some(p) => p.decode_value(response),
+            None    => Err(PidError::Unsupported { mode: mode as u8, pid: pid as u8 })
+        }
+    }
+
+    pub fn decode_response(&self, response: &[u8]) -> Result<DecodedValue, PidError> {
+        //
+        // Décode une réponse en déduisant le mode et le pid de ses deux premiers octets
+        //
+        let mode : u8 = *response.first().ok_or(PidError::Empty)?;
+        let pid  : u8 = *response.get(1).ok_or(PidError::TooShort { expected: 2, received: response.len() })?;
+        if mode < 0x40 { return Err(PidError::ModeMismatch { expected: mode | 0x40, received: mode }); }
+        if mode == 0x42 && !self.contains(0x02, pid as PidLen) {
+            return Ok(self.decode_freeze_frame(response)?.1);
+        }
+        self.decode((mode - 0x40) as ModLen, pid as PidLen, response)
+    }
+
+    pub fn decode_freeze_frame(&self, response: &[u8]) -> Result<(u8, DecodedValue), PidError> {
+        //
+        // Décode une réponse du mode 0x02 avec le pid correspondant du mode 0x01
+        // :return: Numéro de la trame figée et valeur décodée
+        //
+        let (frame, converted) = freeze_frame_to_mode01(response)?;
+        Ok((frame, self.decode(0x01, converted[1] as PidLen, &converted)?))
+    }
+
+    fn register_mode01(&mut self) {
+        self.register(AvailablePids20::new());
+        self.register(StatusSinceDTC::new());
+        self.register(FreezeDTC::new());
+        self.register(FuelSystemStatus::new());
+        self.register(EngineLoad::new());
+        self.register(EngineCoolantTemperature::new());
+        self.register(ShortTermFuelTrim1::new());
+        self.register(LongTermFuelTrim1::new());
+        self.register(ShortTermFuelTrim2::new());
+        self.register(LongTermFuelTrim2::new());
+        self.register(FuelPressure::new());
+        self.register(IntakeManifoldAbsolutePressure::new());
+        self.register(EngineSpeed::new());
+        self.register(VehicleSpeed::new());
+        self.register(TimingAdvance::new());
+        self.register(IntakeAirTemperature::new());
+        self.register(MAFSensor::new());
+        self.register(ThrottlePosition::new());
+        self.register(CommendedSecondaryAirStatus::new());
+        self.register(OxygenSensorPresent::new());
+        self.register(OxygenSensor1::new());
+        self.register(OxygenSensor2::new());
+        self.register(OxygenSensor3::new());
+        self.register(OxygenSensor4::new());
+        self.register(OxygenSensor5::new());
+        self.register(OxygenSensor6::new());
+        self.register(OxygenSensor7::new());
+        self.register(OxygenSensor8::new());
+        self.register(ObdStandardForThisVehicle::new());
+        self.register(OxygenSensorPresent4Banks::new());
+        self.register(AuxiliaryInputStatus::new());
+        self.register(RunTimeSinceStart::new());
+        self.register(AvailablePids40::new());
+        self.register(DistanceWithMIL::new());
+        self.register(FuelRailPressure::new());
+        self.register(FuelRailGaugePressure::new());
+        self.register(OxygenSensorLambda1::new());
+        self.register(OxygenSensorLambda2::new());
+        self.register(OxygenSensorLambda3::new());
+        self.register(OxygenSensorLambda4::new());
+        self.register(OxygenSensorLambda5::new());
+        self.register(OxygenSensorLambda6::new());
+        self.register(OxygenSensorLambda7::new());
+        self.register(OxygenSensorLambda8::new());
+        self.register(CommandedEGR::new());
+        self.register(EGRError::new());
+        self.register(CommandedEvaporativePurge::new());
+        self.register(FuelTankLevelInput::new());
+        self.register(WarmUpsSinceCodesCleared::new());
+        self.register(DistanceSinceCodesCleared::new());
+        self.register(EvapSystemVaporPressure::new());
+        self.register(AbsoluteBarometricPressure::new());
+        self.register(OxygenSensorCurrent1::new());
+        self.register(OxygenSensorCurrent2::new());
+        self.register(OxygenSensorCurrent3::new());
+        self.register(OxygenSensorCurrent4::new());
+        self.register(OxygenSensorCurrent5::new());
+        self.register(OxygenSensorCurrent6::new());
+        self.register(OxygenSensorCurrent7::new());
+        self.register(OxygenSensorCurrent8::new());
+        self.register(CatalystTemperatureBank1Sensor1::new());
+        self.register(CatalystTemperatureBank2Sensor1::new());
+        self.register(CatalystTemperatureBank1Sensor2::new());
+        self.register(CatalystTemperatureBank2Sensor2::new());
+        self.register(AvailablePids60::new());
+        self.register(MonitorStatusThisDriveCycle::new());
+        self.register(ControlModuleVoltage::new());
+        self.register(AbsoluteLoadValue::new());
+        self.register(CommandedAirFuelEquivalenceRatio::new());
+        self.register(RelativeThrottlePosition::new());
+        self.register(AmbientAirTemperature::new());
+        self.register(AbsoluteThrottlePositionB::new());
+        self.register(AbsoluteThrottlePositionC::new());
+        self.register(AcceleratorPedalPositionD::new());
+        self.register(AcceleratorPedalPositionE::new());
+        self.register(AcceleratorPedalPositionF::new());
+        self.register(CommandedThrottleActuator::new());
+        self.register(TimeRunWithMIL::new());
+        self.register(TimeSinceCodesCleared::new());
+        self.register(MaximumSensorValues::new());
+        self.register(MaximumAirFlowRate::new());
+        self.register(FuelTypeUsed::new());
+        self.register(EthanolFuelPercentage::new());
+        self.register(AbsoluteEvapSystemVaporPressure::new());
+        self.register(EvapSystemVaporPressureWide::new());
+        self.register(ShortTermSecondaryOxygenTrimBanks13::new());
+        self.register(LongTermSecondaryOxygenTrimBanks13::new());
+        self.register(ShortTermSecondaryOxygenTrimBanks24::new());
+        self.register(LongTermSecondaryOxygenTrimBanks24::new());
+        self.register(FuelRailAbsolutePressure::new());
+        self.register(RelativeAcceleratorPedalPosition::new());
+        self.register(HybridBatteryPackRemainingLife::new());
+        self.register(EngineOilTemperature::new());
+        self.register(FuelInjectionTiming::new());
+        self.register(EngineFuelRate::new());
+        self.register(EmissionRequirementsForThisVehicle::new());
+        self.register(AvailablePids80::new());
+        self.register(DriverDemandEnginePercentTorque::new());
+        self.register(ActualEnginePercentTorque::new());
+        self.register(EngineReferenceTorque::new());
+        self.register(EnginePercentTorqueData::new());
+        self.register(AuxiliaryInputOutputSupported::new());
+        self.register(MassAirFlowSensors::new());
+        self.register(EngineCoolantTemperatureSensors::new());
+        self.register(IntakeAirTemperatureSensors::new());
+        self.register(CommandedEGRAndEGRError::new());
+        self.register(CommandedDieselIntakeAirFlowControl::new());
+        self.register(ExhaustGasRecirculationTemperature::new());
+        self.register(CommandedThrottleActuatorControl::new());
+        self.register(FuelPressureControlSystem::new());
+        self.register(InjectionPressureControlSystem::new());
+        self.register(TurbochargerCompressorInletPressure::new());
+        self.register(BoostPressureControlSystem::new());
+        self.register(VariableGeometryTurboControlSystem::new());
+        self.register(WastegateControl::new());
+        self.register(ExhaustPressure::new());
+        self.register(TurbochargerRpm::new());
+        self.register(TurbochargerATemperature::new());
+        self.register(TurbochargerBTemperature::new());
+        self.register(ChargeAirCoolerTemperature::new());
+        self.register(ExhaustGasTemperatureBank1::new());
+        self.register(ExhaustGasTemperatureBank2::new());
+        self.register(DieselParticulateFilter1::new());
+        self.register(DieselParticulateFilter2::new());
+        self.register(DieselParticulateFilterTemperature::new());
+        self.register(NoxNteControlAreaStatus::new());
+        self.register(PmNteControlAreaStatus::new());
+        self.register(EngineRunTime::new());
+        self.register(AvailablePidsA0::new());
+        self.register(EngineRunTimeForAecd1::new());
+        self.register(EngineRunTimeForAecd2::new());
+        self.register(NoxSensor::new());
+        self.register(ManifoldSurfaceTemperature::new());
+        self.register(NoxReagentSystemStatus::new());
+        self.register(ParticulateMatterSensor::new());
+        self.register(IntakeManifoldAbsolutePressureSensors::new());
+        self.register(ScrInducementSystem::new());
+        self.register(EngineRunTimeForAecd3::new());
+        self.register(EngineRunTimeForAecd4::new());
+        self.register(DieselAftertreatment::new());
+        self.register(OxygenSensorWideRange::new());
+        self.register(RelativeThrottlePositionG::new());
+        self.register(EngineFrictionPercentTorque::new());
+        self.register(ParticulateMatterSensorOutput::new());
+        self.register(WwhObdVehicleInformation::new());
+        self.register(WwhObdCountersInformation::new());
+        self.register(FuelSystemControl::new());
+        self.register(WwhObdCountersSupport::new());
+        self.register(NoxWarningAndInducementSystem::new());
+        self.register(ExhaustGasTemperatureSensorsBank1::new());
+        self.register(ExhaustGasTemperatureSensorsBank2::new());
+        self.register(HybridVehicleSystemData::new());
+        self.register(DieselExhaustFluidSensorData::new());
+        self.register(OxygenSensorData::new());
+        self.register(EngineFuelRateMass::new());
+        self.register(EngineExhaustFlowRate::new());
+        self.register(FuelSystemPercentageUse::new());
+        self.register(AvailablePidsC0::new());
+        self.register(NoxSensorCorrectedConcentration::new());
+        self.register(CylinderFuelRate::new());
+        self.register(EvapSystemVaporPressureSensors::new());
+        self.register(TransmissionActualGear::new());
+        self.register(CommandedDieselExhaustFluidDosing::new());
+        self.register(Odometer::new());
+        self.register(NoxSensorConcentration34::new());
+        self.register(NoxSensorCorrectedConcentration34::new());
+        self.register(AbsDisableSwitchState::new());
+        //
+        // 0xc3 et 0xc4 ne sont pas enregistrés : leur contenu n'est pas normalisé et dépend du constructeur
+        //
+        self.register(AvailablePidsE0::new());
+    }
+}
+
+// Fonctions
+fn supported_flags(flags: Vec<(&'static str, Option<bool>)>) -> DecodedValue {
+    DecodedValue::Bitfield(flags.into_iter().filter_map(|(name, flag)| flag.map(|f| (name, f))).collect())
+}
+
+fn split_pair_unit(unit: Option<&'static str>) -> (Option<&'static str>, Option<&'static str>) {
+    //
+    // Découpe une unité de la forme "(volts, %)" en deux unités
+    //
+    let inner : &'static str = match unit.and_then(|u| u.strip_prefix('(')).and_then(|u| u.strip_suffix(')')) {
+        Some(inner) => inner,
+        None        => return (unit, unit)
+    };
+    match inner.split_once(',') {
+        Some((first, second)) => (Some(first.trim()), Some(second.trim())),
+        None                  => (unit, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_registry_decodes_by_mode_and_pid() {
+        let registry : PidRegistry = PidRegistry::standard();
+        assert!(registry.contains(0x01, 0x0c) && !registry.contains(0x02, 0x0c));
+        assert_eq!(registry.mode(0x01).count(), registry.len());
+        assert_eq!(registry.get(0x01, 0x0d).map(|p| p.info().description), Some("Vitesse du véhicule"));
+        assert_eq!(registry.decode(0x01, 0x0c, &[0x41, 0x0c, 0x1a, 0xf8]), Ok(DecodedValue::Scalar(1726.0, Some("rpm"))));
+        assert_eq!(registry.decode_response(&[0x41, 0x0d, 0x32]), Ok(DecodedValue::Scalar(50.0, Some("km/h"))));
+        assert_eq!(registry.decode_response(&[0x41, 0x0d, 0x32, 0x00]), Err(PidError::TooLong { expected: 1, received: 2 }));
+    }
+
+    #[test]
+    fn freeze_frames_fall_back_to_mode01() {
+        let registry : PidRegistry = PidRegistry::standard();
+        assert_eq!(registry.decode_response(&[0x42, 0x0c, 0x00, 0x1a, 0xf8]), Ok(DecodedValue::Scalar(1726.0, Some("rpm"))));
+        assert_eq!(registry.decode_freeze_frame(&[0x42, 0x0d, 0x01, 0x32]), Ok((0x01, DecodedValue::Scalar(50.0, Some("km/h")))));
+
+        let mut registry : PidRegistry = PidRegistry::new();
+        registry.register(FreezeFrame::new(VehicleSpeed::new(), 0));
+        assert_eq!(registry.decode_response(&[0x42, 0x0d, 0x00, 0x32]), Ok(DecodedValue::Scalar(50.0, Some("km/h"))));
+    }
+
+    #[test]
+    fn unknown_pids_and_modes_are_errors() {
+        let registry : PidRegistry = PidRegistry::standard();
+        assert_eq!(registry.decode_response(&[0x41, 0xff, 0x00]), Err(PidError::Unsupported { mode: 0x01, pid: 0xff }));
+        assert_eq!(registry.decode_response(&[0x49, 0x02, 0x01]), Err(PidError::Unsupported { mode: 0x09, pid: 0x02 }));
+        assert_eq!(registry.decode_response(&[0x42, 0xff, 0x00, 0x00]), Err(PidError::Unsupported { mode: 0x01, pid: 0xff }));
+        assert_eq!(registry.decode_response(&[0x01, 0x0c]), Err(PidError::ModeMismatch { expected: 0x41, received: 0x01 }));
+        assert_eq!(registry.decode_response(&[0x41]), Err(PidError::TooShort { expected: 2, received: 1 }));
+        assert_eq!(registry.decode_response(&[]), Err(PidError::Empty));
+        assert!(PidRegistry::new().is_empty());
+    }
+
+    #[test]
+    fn outputs_convert_to_decoded_values() {
+        let registry : PidRegistry = PidRegistry::standard();
+        assert_eq!(registry.decode_response(&[0x41, 0x14, 0x64, 0x80]), Ok(DecodedValue::Pair(
+            Box::new(DecodedValue::Scalar(0.5, Some("volts"))),
+            Box::new(DecodedValue::Scalar(0.0, Some("%")))
+        )));
+        assert_eq!(Some(3u8).into_decoded(Some("km")), DecodedValue::Scalar(3.0, Some("km")));
+        assert_eq!(None::<u8>.into_decoded(Some("km")), DecodedValue::Empty);
+        assert_eq!(vec![1u16, 2].into_decoded(None), DecodedValue::List(vec![DecodedValue::Scalar(1.0, None), DecodedValue::Scalar(2.0, None)]));
+        assert_eq!(true.into_decoded(None), DecodedValue::Label("true".to_string()));
+        assert_eq!(().into_decoded(Some("%")), DecodedValue::Empty);
+    }
+
+    #[test]
+    fn pair_units_are_split() {
+        assert_eq!(split_pair_unit(Some("(volts, %)")), (Some("volts"), Some("%")));
+        assert_eq!(split_pair_unit(Some("(ratio,mA)")), (Some("ratio"), Some("mA")));
+        assert_eq!(split_pair_unit(Some("kPa")), (Some("kPa"), Some("kPa")));
+        assert_eq!(split_pair_unit(Some("(kPa)")), (Some("(kPa)"), Some("(kPa)")));
+        assert_eq!(split_pair_unit(None), (None, None));
+    }
+}
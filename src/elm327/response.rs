@@ -0,0 +1,260 @@
+// Uses
+use std::fmt;
+use crate::elm327::definition::reply_lines;
+use crate::elm327::frame::FrameError;
+use crate::elm327::isotp::*;
+use crate::elm327::types::*;
+
+// Enums
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Elm327Error {
+    //
+    // Erreurs remontées par l'adaptateur à la place des données
+    //
+    NoData,
+    UnknownCommand,
+    UnableToConnect,
+    BusInit(String),
+    BusBusy,
+    BusError,
+    CanError,
+    DataError,
+    RxError,
+    FeedbackError,
+    BufferFull,
+    Stopped,
+    ActivityAlert,
+    LowVoltageReset,
+    Internal(u8),
+    InvalidData(String),
+    Reassembly { error: IsoTpError, complete: Vec<EcuResponse> },
+    Frame(FrameError),
+    Empty
+}
+
+impl fmt::Display for Elm327Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Elm327Error::NoData          => write!(f, "Aucune donnée reçue du véhicule (NO DATA)"),
+            Elm327Error::UnknownCommand  => write!(f, "Commande non comprise par l'adaptateur (?)"),
+            Elm327Error::UnableToConnect => write!(f, "Aucun protocole trouvé (UNABLE TO CONNECT)"),
+            Elm327Error::BusInit(s)      => write!(f, "Échec de l'initialisation du bus ({})", s),
+            Elm327Error::BusBusy         => write!(f, "Bus occupé (BUS BUSY)"),
+            Elm327Error::BusError        => write!(f, "Erreur de bus (BUS ERROR)"),
+            Elm327Error::CanError        => write!(f, "Erreur CAN (CAN ERROR)"),
+            Elm327Error::DataError       => write!(f, "Données invalides reçues (DATA ERROR)"),
+            Elm327Error::RxError         => write!(f, "Erreur de réception (RX ERROR)"),
+            Elm327Error::FeedbackError   => write!(f, "Erreur de retour du bus (FB ERROR)"),
+            Elm327Error::BufferFull      => write!(f, "Tampon de l'adaptateur plein (BUFFER FULL)"),
+            Elm327Error::Stopped         => write!(f, "Requête interrompue (STOPPED)"),
+            Elm327Error::ActivityAlert   => write!(f, "Aucune activité sur le bus (ACT ALERT)"),
+            Elm327Error::LowVoltageReset => write!(f, "Redémarrage de l'adaptateur sur tension basse (LV RESET)"),
+            Elm327Error::Internal(n)     => write!(f, "Erreur interne de l'adaptateur (ERR{:02})", n),
+            Elm327Error::InvalidData(s)  => write!(f, "Réponse illisible : {:?}", s),
+            Elm327Error::Reassembly { error, complete } if complete.is_empty() => write!(f, "{}", error),
+            Elm327Error::Reassembly { error, complete } => write!(f, "{} ({} message(s) complet(s) reçu(s) d'autres calculateurs)", error, complete.len()),
+            Elm327Error::Frame(e)        => write!(f, "{}", e),
+            Elm327Error::Empty           => write!(f, "Réponse vide")
+        }
+    }
+}
+
+impl std::error::Error for Elm327Error {}
+
+impl From<IsoTpError> for Elm327Error {
+    fn from(e: IsoTpError) -> Self { Elm327Error::Reassembly { error: e, complete: vec![] } }
+}
+
+impl From<FrameError> for Elm327Error {
+    fn from(e: FrameError) -> Self { Elm327Error::Frame(e) }
+}
+
+// Structs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcuResponse {
+    //
+    // Message de données attribué au calculateur qui l'a émis
+    // :field ecu:  Adresse du calculateur (rang de la réponse quand les entêtes sont désactivés)
+    // :field data: Octets de données, mode et pid compris
+    //
+    pub ecu:  EcuAddress,
+    pub data: Vec<u8>
+}
+
+// Fonctions
+pub fn decode_error(line: &str) -> Option<Elm327Error> {
+    let upper : String = line.to_uppercase();
+    let upper : &str   = upper.trim_start_matches('<');
+    let error : Elm327Error = match upper {
+        "?"                 => Elm327Error::UnknownCommand,
+        "NO DATA"           => Elm327Error::NoData,
+        "UNABLE TO CONNECT" => Elm327Error::UnableToConnect,
+        "BUS BUSY"          => Elm327Error::BusBusy,
+        "BUS ERROR"         => Elm327Error::BusError,
+        "CAN ERROR"         => Elm327Error::CanError,
+        "DATA ERROR"        => Elm327Error::DataError,
+        "RX ERROR"          => Elm327Error::RxError,
+        "FB ERROR"          => Elm327Error::FeedbackError,
+        "BUFFER FULL"       => Elm327Error::BufferFull,
+        "STOPPED"           => Elm327Error::Stopped,
+        "ACT ALERT"         => Elm327Error::ActivityAlert,
+        "LV RESET"          => Elm327Error::LowVoltageReset,
+        _ if upper.starts_with("BUS INIT") && upper.ends_with("ERROR") => Elm327Error::BusInit(line.to_string()),
+        _ if upper.starts_with("ERR") => match upper[3..].parse::<u8>() {
+            Ok(n)  => Elm327Error::Internal(n),
+            Err(_) => return None
+        },
+        _ if upper.ends_with("DATA ERROR") => Elm327Error::DataError,
+        _ => return None
+    };
+    Some(error)
+}
+
+pub fn is_information(line: &str) -> bool {
+    let upper : String = line.to_uppercase();
+    upper.starts_with("SEARCHING") || upper == "OK" || (upper.starts_with("BUS INIT") && !upper.ends_with("ERROR"))
+}
+
+pub fn decode_hex(line: &str) -> Option<Vec<u8>> {
+    //
+    // Convertit une ligne hexadécimale, avec ou sans espaces, en octets
+    //
+    let digits : Vec<u8> = line.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if digits.is_empty() || digits.len() % 2 != 0 { return None; }
+    digits.chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low  = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+fn split_segment(line: &str) -> Option<(usize, &str)> {
+    //
+    // Découpe une ligne "N: xx xx ..." d'une réponse CAN multi-trames formatée
+    //
+    let (index, rest) = line.split_once(':')?;
+    let index : &str  = index.trim();
+    if index.len() != 1 { return None; }
+    Some((usize::from_str_radix(index, 16).ok()?, rest))
+}
+
+fn parse_length(line: &str) -> Option<usize> {
+    if line.len() != 3 || !line.bytes().all(|b| b.is_ascii_hexdigit()) { return None; }
+    usize::from_str_radix(line, 16).ok()
+}
+
+pub fn reply_error(command: &str, raw: &str) -> Option<Elm327Error> {
+    //
+    // Première erreur signalée par l'adaptateur dans une réponse, quel que soit l'affichage des entêtes
+    //
+    reply_lines(command, raw).iter().find_map(|line| decode_error(line))
+}
+
+pub fn finish_reassembly(reassembler: Reassembler) -> Result<Vec<EcuResponse>, Elm327Error> {
+    //
+    // Messages réassemblés ; en cas d'erreur, la première est retournée avec les messages complets des autres calculateurs
+    //
+    let (complete, errors) = reassembler.finish();
+    match errors.into_iter().next() {
+        Some(error)                 => Err(Elm327Error::Reassembly { error, complete }),
+        None if complete.is_empty() => Err(Elm327Error::Empty),
+        None                        => Ok(complete)
+    }
+}
+
+pub fn parse_response(command: &str, raw: &str) -> Result<Vec<Vec<u8>>, Elm327Error> {
+    //
+    // Transforme la réponse brute de l'adaptateur (entêtes masqués) en une liste de messages
+    // :param command: Commande envoyée, pour retirer un éventuel écho
+    // :param raw:     Texte reçu avant le prompt '>'
+    // :return:        Un message (octets de données) par ligne, les réponses CAN multi-trames étant réassemblées
+    // Les erreurs de réassemblage sont conservées par le réassembleur et remontées par finish_reassembly
+    //
+    let lines : Vec<String> = reply_lines(command, raw);
+    let mut reassembler : Reassembler = Reassembler::new();
+
+    for line in lines.iter().map(|l| l.as_str()) {
+        if is_information(line) { continue; }
+        if let Some(error) = decode_error(line) { return Err(error); }
+
+        if let Some(length) = parse_length(line) {
+            let _ = reassembler.first(0, length);
+            continue;
+        }
+
+        if let Some((index, rest)) = split_segment(line) {
+            let bytes : Vec<u8> = decode_hex(rest).ok_or_else(|| Elm327Error::InvalidData(line.to_string()))?;
+            let _ = reassembler.consecutive(0, index as u8, &bytes);
+            continue;
+        }
+
+        reassembler.single(0, decode_hex(line).ok_or_else(|| Elm327Error::InvalidData(line.to_string()))?);
+    }
+
+    Ok(finish_reassembly(reassembler)?.into_iter().map(|r| r.data).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapter_errors_are_typed() {
+        let cases : Vec<(&str, Elm327Error)> = vec![
+            ("?",                     Elm327Error::UnknownCommand),
+            ("NO DATA",               Elm327Error::NoData),
+            ("UNABLE TO CONNECT",     Elm327Error::UnableToConnect),
+            ("BUS INIT: ...ERROR",    Elm327Error::BusInit("BUS INIT: ...ERROR".to_string())),
+            ("BUS BUSY",              Elm327Error::BusBusy),
+            ("BUS ERROR",             Elm327Error::BusError),
+            ("CAN ERROR",             Elm327Error::CanError),
+            ("DATA ERROR",            Elm327Error::DataError),
+            ("<DATA ERROR",           Elm327Error::DataError),
+            ("41 0D 32 <DATA ERROR",  Elm327Error::DataError),
+            ("<RX ERROR",             Elm327Error::RxError),
+            ("FB ERROR",              Elm327Error::FeedbackError),
+            ("BUFFER FULL",           Elm327Error::BufferFull),
+            ("STOPPED",               Elm327Error::Stopped),
+            ("ACT ALERT",             Elm327Error::ActivityAlert),
+            ("LV RESET",              Elm327Error::LowVoltageReset),
+            ("ERR94",                 Elm327Error::Internal(94))
+        ];
+        for (line, error) in cases.into_iter() {
+            assert_eq!(decode_error(line), Some(error.clone()), "{:?}", line);
+            assert_eq!(parse_response("010D", &format!("010D\r{}\r\r", line)), Err(error), "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn garbage_is_rejected_without_panic() {
+        let cases : Vec<(&str, &str)> = vec![
+            ("echo et recherche seuls",    "0100\rSEARCHING...\r\r"),
+            ("réponse vide",               ""),
+            ("hexadécimal impair",         "41 0D 3\r"),
+            ("caractère non hexadécimal",  "41 0D ZZ\r"),
+            ("caractère non ascii",        "41 0D é2\r"),
+            ("segment 0: vide",            "0:\r"),
+            ("segment sans longueur",      "0: 49 02 01 31 44 34\r"),
+            ("longueur sans segment",      "014\r"),
+            ("ERR non numérique",          "ERRXY\r"),
+            ("ERR trop grand",             "ERR999\r")
+        ];
+        for (case, raw) in cases.into_iter() {
+            assert!(parse_response("0100", raw).is_err(), "{}", case);
+        }
+        assert_eq!(decode_error("ERRXY"), None);
+        assert_eq!(decode_hex("41 0"), None);
+        assert_eq!(decode_hex(""), None);
+    }
+
+    #[test]
+    fn truncated_replies_never_panic() {
+        let raw : &str = "0902\rSEARCHING...\r014\r0: 49 02 01 31 44 34\r1: 47 50 30 30 52 35 35\r2: 42 31 32 33 34 35 36\r\r";
+        assert_eq!(parse_response("0902", raw).map(|m| m[0].len()), Ok(0x14));
+        for end in (0..raw.len()).filter(|i| raw.is_char_boundary(*i)) {
+            let _ = parse_response("0902", &raw[..end]);
+        }
+    }
+}
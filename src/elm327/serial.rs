@@ -0,0 +1,255 @@
+// Uses
+use std::convert::TryFrom;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use crate::elm327::transport::*;
+
+// Enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaudRate {
+    B9600, B38400, B115200, B500000
+}
+
+impl BaudRate {
+    pub fn value(&self) -> u32 {
+        match self {
+            BaudRate::B9600   => 9600,
+            BaudRate::B38400  => 38400,
+            BaudRate::B115200 => 115200,
+            BaudRate::B500000 => 500000
+        }
+    }
+
+    fn speed(&self) -> libc::speed_t {
+        match self {
+            BaudRate::B9600   => libc::B9600,
+            BaudRate::B38400  => libc::B38400,
+            BaudRate::B115200 => libc::B115200,
+            BaudRate::B500000 => libc::B500000
+        }
+    }
+}
+
+impl TryFrom<u32> for BaudRate {
+    type Error = TransportError;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            9600   => Ok(BaudRate::B9600),
+            38400  => Ok(BaudRate::B38400),
+            115200 => Ok(BaudRate::B115200),
+            500000 => Ok(BaudRate::B500000),
+            _      => Err(TransportError::InvalidBaudRate(value))
+        }
+    }
+}
+
+// Structs
+pub struct SerialTransport {
+    //
+    // Transport vers un elm327 branché sur un port série (USB ou bluetooth rfcomm)
+    // :field file:    Descripteur du port série configuré en mode brut
+    // :field baud:    Vitesse de transmission courante
+    // :field timeout: Délai maximal d'attente du prompt
+    // :field pending: Octets reçus et pas encore rendus, dont ceux arrivés après le dernier prompt
+    //
+    file:    File,
+    baud:    BaudRate,
+    timeout: Duration,
+    pending: Vec<u8>
+}
+
+impl SerialTransport {
+    pub fn open<P: AsRef<Path>>(path: P, baud: BaudRate) -> Result<Self, TransportError> {
+        let file : File = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(libc::O_NOCTTY | libc::O_NONBLOCK)
+            .open(path)?;
+        Self::from_file(file, baud)
+    }
+
+    pub fn from_file(file: File, baud: BaudRate) -> Result<Self, TransportError> {
+        let mut transport : SerialTransport = SerialTransport { file, baud, timeout: DEFAULT_TIMEOUT, pending: vec![] };
+        transport.configure()?;
+        Ok(transport)
+    }
+
+    pub fn baud_rate(&self) -> BaudRate {
+        self.baud
+    }
+
+    pub fn set_baud_rate(&mut self, baud: BaudRate) -> Result<(), TransportError> {
+        self.baud = baud;
+        self.configure()
+    }
+
+    fn configure(&mut self) -> Result<(), TransportError> {
+        let fd = self.file.as_raw_fd();
+        unsafe {
+            let mut tio : libc::termios = std::mem::zeroed();
+            if libc::tcgetattr(fd, &mut tio) != 0 { return Err(io::Error::last_os_error().into()); }
+            libc::cfmakeraw(&mut tio);
+            tio.c_cflag |= libc::CLOCAL | libc::CREAD;
+            tio.c_cflag &= !(libc::CSTOPB | libc::PARENB | libc::CRTSCTS);
+            tio.c_cc[libc::VMIN]  = 0;
+            tio.c_cc[libc::VTIME] = 0;
+            if libc::cfsetispeed(&mut tio, self.baud.speed()) != 0 { return Err(io::Error::last_os_error().into()); }
+            if libc::cfsetospeed(&mut tio, self.baud.speed()) != 0 { return Err(io::Error::last_os_error().into()); }
+            if libc::tcsetattr(fd, libc::TCSANOW, &tio) != 0 { return Err(io::Error::last_os_error().into()); }
+            libc::tcflush(fd, libc::TCIOFLUSH);
+        }
+        Ok(())
+    }
+
+    fn wait(&self, events: libc::c_short, remaining: Duration) -> Result<bool, TransportError> {
+        //
+        // Attend que le port soit prêt en lecture (POLLIN) ou en écriture (POLLOUT) pendant au plus remaining
+        // :return: false si le délai a expiré ou si l'attente a été interrompue par un signal
+        //
+        let mut pfd : libc::pollfd = libc::pollfd { fd: self.file.as_raw_fd(), events, revents: 0 };
+        let millis  : libc::c_int  = remaining.as_millis().min(libc::c_int::MAX as u128) as libc::c_int;
+        let ready   : libc::c_int  = unsafe { libc::poll(&mut pfd, 1, millis) };
+        if ready < 0 {
+            let e : io::Error = io::Error::last_os_error();
+            if e.kind() == io::ErrorKind::Interrupted { return Ok(false); }
+            return Err(TransportError::Io(e));
+        }
+        if ready > 0 && pfd.revents & (libc::POLLHUP | libc::POLLERR) != 0 && pfd.revents & events == 0 {
+            return Err(TransportError::Closed);
+        }
+        Ok(ready > 0)
+    }
+}
+
+impl Transport for SerialTransport {
+    fn write_command(&mut self, command: &str) -> Result<(), TransportError> {
+        let deadline    : Instant = Instant::now() + self.timeout;
+        let encoded     : Vec<u8> = encode_command(command);
+        let mut written : usize   = 0;
+        while written < encoded.len() {
+            match self.file.write(&encoded[written..]) {
+                Ok(0)  => return Err(TransportError::Closed),
+                Ok(n)  => written += n,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    let now : Instant = Instant::now();
+                    if now >= deadline { return Err(TransportError::Timeout); }
+                    self.wait(libc::POLLOUT, deadline - now)?;
+                },
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) if e.raw_os_error() == Some(libc::EIO)    => return Err(TransportError::Closed),
+                Err(e) => return Err(TransportError::Io(e))
+            }
+        }
+        self.file.flush()?;
+        Ok(())
+    }
+
+    fn read_until_prompt(&mut self) -> Result<String, TransportError> {
+        //
+        // Le port étant prêt en lecture, une lecture vide ou EIO signifie que la ligne a été raccrochée
+        //
+        let deadline : Instant  = Instant::now() + self.timeout;
+        let mut buf  : [u8; 64] = [0; 64];
+        loop {
+            if let Some(response) = take_response(&mut self.pending) { return Ok(response); }
+            let now : Instant = Instant::now();
+            if now >= deadline { return Err(TransportError::Timeout); }
+            if !self.wait(libc::POLLIN, deadline - now)? { continue; }
+            match self.file.read(&mut buf) {
+                Ok(0)  => return Err(TransportError::Closed),
+                Ok(n)  => self.pending.extend_from_slice(&buf[..n]),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) if e.raw_os_error() == Some(libc::EIO) => return Err(TransportError::Closed),
+                Err(e) => return Err(TransportError::Io(e))
+            }
+        }
+    }
+
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::os::unix::io::FromRawFd;
+
+    fn open_pty() -> (File, SerialTransport) {
+        //
+        // Paire pty : le maître joue le rôle de l'adaptateur, l'esclave est ouvert comme un port série
+        //
+        unsafe {
+            let fd : libc::c_int = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+            assert!(fd >= 0, "posix_openpt : {}", io::Error::last_os_error());
+            assert_eq!(libc::grantpt(fd), 0);
+            assert_eq!(libc::unlockpt(fd), 0);
+            let mut name : [libc::c_char; 128] = [0; 128];
+            assert_eq!(libc::ptsname_r(fd, name.as_mut_ptr(), name.len()), 0);
+            let path : String = CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned();
+            let master : File = File::from_raw_fd(fd);
+            let mut transport : SerialTransport = SerialTransport::open(path, BaudRate::B38400).unwrap();
+            transport.set_timeout(Duration::from_millis(200));
+            (master, transport)
+        }
+    }
+
+    #[test]
+    fn send_reads_reply_up_to_prompt() {
+        let (mut adapter, mut transport) = open_pty();
+        transport.write_command("0100").unwrap();
+        let mut command : [u8; 5] = [0; 5];
+        adapter.read_exact(&mut command).unwrap();
+        assert_eq!(&command, b"0100\r");
+
+        adapter.write_all(b"SEARCHING...\r41 00 BE").unwrap();
+        adapter.write_all(b" 1F A8 13\r\r>").unwrap();
+        assert_eq!(transport.read_until_prompt().unwrap(), "SEARCHING...\r41 00 BE 1F A8 13\r\r");
+    }
+
+    #[test]
+    fn bytes_after_prompt_are_kept_for_next_reply() {
+        let (mut adapter, mut transport) = open_pty();
+        adapter.write_all(b"OK\r\r>41 0D 32\r\r>").unwrap();
+        assert_eq!(transport.read_until_prompt().unwrap(), "OK\r\r");
+        assert_eq!(transport.read_until_prompt().unwrap(), "41 0D 32\r\r");
+    }
+
+    #[test]
+    fn read_without_prompt_times_out() {
+        let (mut adapter, mut transport) = open_pty();
+        adapter.write_all(b"41 00 BE 1F").unwrap();
+        let start : Instant = Instant::now();
+        assert!(matches!(transport.read_until_prompt(), Err(TransportError::Timeout)));
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn write_to_stalled_adapter_times_out() {
+        //
+        // L'adaptateur ne lit plus : le tampon du pty se remplit et l'écriture doit échouer au bout du délai
+        //
+        let (_adapter, mut transport) = open_pty();
+        let command : String = "0".repeat(1 << 20);
+        let start : Instant = Instant::now();
+        assert!(matches!(transport.write_command(&command), Err(TransportError::Timeout)));
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn closed_port_is_reported() {
+        let (adapter, mut transport) = open_pty();
+        drop(adapter);
+        assert!(matches!(transport.read_until_prompt(), Err(TransportError::Closed)));
+        assert!(matches!(transport.write_command("ATZ"), Err(TransportError::Closed)));
+    }
+}
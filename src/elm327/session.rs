@@ -0,0 +1,909 @@
+// Uses
+use std::fmt;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use crate::elm327::decoder::IgnitionType;
+use crate::elm327::definition::*;
+use crate::elm327::dtc::*;
+use crate::elm327::frame::*;
+use crate::elm327::info::*;
+use crate::elm327::monitoring::*;
+use crate::elm327::pids::*;
+use crate::elm327::protocol::ObdProtocol;
+use crate::elm327::registry::*;
+use crate::elm327::supported::*;
+use crate::elm327::types::*;
+use crate::elm327::response::*;
+use crate::elm327::transport::*;
+
+// Constantes
+pub const SEARCH_TIMEOUT : Duration = Duration::from_secs(20);
+pub const EVAP_LEAK_TEST : u8       = 0x01;
+pub const MAX_PIDS       : usize    = 6;
+
+// Enums
+#[derive(Debug)]
+pub enum SessionError {
+    Transport(TransportError),
+    At(AtError),
+    Adapter(Elm327Error),
+    Pid(PidError),
+    NotInitialized,
+    ConnectionFailed(String),
+    UnsupportedMode(u8),
+    EngineRunning(f64),
+    EngineSpeedUnavailable,
+    RequestTooLong { maximum: usize, received: usize },
+    ClearRejected { acknowledged: Vec<EcuAddress>, rejected: Vec<(EcuAddress, NegativeResponseCode)> },
+    ClearPending { acknowledged: Vec<EcuAddress>, pending: Vec<EcuAddress> }
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionError::Transport(e)        => write!(f, "{}", e),
+            SessionError::At(e)               => write!(f, "{}", e),
+            SessionError::Adapter(e)          => write!(f, "{}", e),
+            SessionError::Pid(e)              => write!(f, "{}", e),
+            SessionError::NotInitialized      => write!(f, "La session n'a pas été initialisée"),
+            SessionError::ConnectionFailed(r) => write!(f, "Impossible de communiquer avec le véhicule : {:?}", r),
+            SessionError::UnsupportedMode(m)  => write!(f, "Mode 0x{:02x} non disponible avec le protocole négocié", m),
+            SessionError::EngineRunning(rpm)  => write!(f, "Effacement refusé, moteur tournant ({} rpm)", rpm),
+            SessionError::EngineSpeedUnavailable =>
+                write!(f, "Effacement refusé, régime moteur indisponible : confirmer avec allow_engine_running"),
+            SessionError::RequestTooLong { maximum, received } =>
+                write!(f, "Requête trop longue : {} octets de données, {} au maximum", received, maximum),
+            SessionError::ClearRejected { acknowledged, rejected } => {
+                write!(f, "Effacement refusé par {} calculateur(s) ({} acquittement(s))", rejected.len(), acknowledged.len())?;
+                for (ecu, code) in rejected.iter() { write!(f, " ; calculateur {} : {}", ecu, code)?; }
+                Ok(())
+            },
+            SessionError::ClearPending { acknowledged, pending } =>
+                write!(f, "Effacement non confirmé : réponse finale attendue de {} calculateur(s) ({} acquittement(s))", pending.len(), acknowledged.len())
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+impl From<TransportError> for SessionError {
+    fn from(e: TransportError) -> Self { SessionError::Transport(e) }
+}
+
+impl From<AtError> for SessionError {
+    fn from(e: AtError) -> Self { SessionError::At(e) }
+}
+
+impl From<Elm327Error> for SessionError {
+    fn from(e: Elm327Error) -> Self { SessionError::Adapter(e) }
+}
+
+impl From<PidError> for SessionError {
+    fn from(e: PidError) -> Self { SessionError::Pid(e) }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlOutcome {
+    //
+    // Réponse d'un calculateur à une demande de contrôle (mode 0x08)
+    // :variant Accepted: Réponse positive, avec les octets suivant le TID
+    // :variant Rejected: Réponse négative
+    //
+    Accepted(Vec<u8>),
+    Rejected(NegativeResponseCode)
+}
+
+// Structs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControlConfirmation {
+    //
+    // Jeton exigé par run_control_test, construit uniquement par confirmed()
+    //
+    _private: ()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClearConfirmation {
+    //
+    // Jeton exigé par clear_dtcs, l'effacement réinitialisant aussi les moniteurs de préparation
+    // :field allow_engine_running: Autorise l'effacement moteur tournant
+    //
+    allow_engine_running: bool
+}
+
+pub struct Elm327<T: Transport> {
+    //
+    // Session avec un elm327
+    // :field transport: Lien vers l'adaptateur
+    // :field headers:   Affichage des entêtes (ATH1) demandé lors de l'initialisation
+    // :field version:   Version annoncée par l'adaptateur après ATZ
+    // :field protocol:  Protocole négocié (ATDPN) et s'il a été trouvé par recherche automatique
+    //
+    transport: T,
+    headers:   bool,
+    version:   Option<String>,
+    protocol:  Option<(bool, ObdProtocol)>
+}
+
+impl ClearConfirmation {
+    pub fn confirmed() -> Self {
+        ClearConfirmation { allow_engine_running: false }
+    }
+
+    pub fn allow_engine_running(self) -> Self {
+        ClearConfirmation { allow_engine_running: true }
+    }
+}
+
+impl ControlConfirmation {
+    //
+    // Jeton exigé par run_control_test, les tests du mode 0x08 modifiant l'état du véhicule
+    // (fermeture de l'électrovanne de mise à l'air du canister pour TID 0x01, ...)
+    //
+    pub fn confirmed() -> Self {
+        ControlConfirmation { _private: () }
+    }
+}
+
+impl<T: Transport> Elm327<T> {
+    pub fn new(transport: T) -> Self {
+        Elm327 { transport, headers: false, version: None, protocol: None }
+    }
+
+    pub fn connect(transport: T) -> Result<Self, SessionError> {
+        let mut session : Elm327<T> = Self::new(transport);
+        session.initialize()?;
+        Ok(session)
+    }
+
+    pub fn set_headers(&mut self, headers: bool) {
+        self.headers = headers;
+    }
+
+    pub fn headers(&self) -> bool {
+        self.headers
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn protocol(&self) -> Option<ObdProtocol> {
+        self.protocol.map(|(_, protocol)| protocol)
+    }
+
+    pub fn protocol_automatic(&self) -> Option<bool> {
+        self.protocol.map(|(automatic, _)| automatic)
+    }
+
+    pub fn is_can(&self) -> bool {
+        self.protocol().is_some_and(|protocol| protocol.is_can())
+    }
+
+    pub fn transport(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    pub fn into_transport(self) -> T {
+        self.transport
+    }
+
+    pub fn initialize(&mut self) -> Result<(), SessionError> {
+        //
+        // Séquence de démarrage standard :
+        // ATZ, ATE0, ATL0, ATH0/1, ATSP0, requête 0100 pour forcer la recherche du protocole puis ATDPN
+        //
+        self.version  = None;
+        self.protocol = None;
+        if let AtReply::Version(version) = self.at(AtCommand::Reset)? {
+            self.version = Some(version);
+        }
+        self.at(AtCommand::Echo(false))?;
+        self.at(AtCommand::Linefeeds(false))?;
+        self.at(AtCommand::Headers(self.headers))?;
+        self.at(AtCommand::SetProtocol(ObdProtocol::Automatic))?;
+
+        let timeout : Duration = self.transport.timeout();
+        self.transport.set_timeout(timeout.max(SEARCH_TIMEOUT));
+        let reply : Result<String, TransportError> = self.transport.send("0100");
+        self.transport.set_timeout(timeout);
+        let reply : String = reply?;
+        if let Some(error) = reply_error("0100", &reply) { return Err(error.into()); }
+
+        if let AtReply::ProtocolNumber { automatic, protocol } = self.at(AtCommand::DescribeProtocolNumber)? {
+            self.protocol = Some((automatic, protocol));
+        }
+        let connected : Result<bool, SessionError> = self.parse_ecus("0100", &reply)
+            .map(|responses| responses.iter().any(|r| r.data.starts_with(&[0x41, 0x00])));
+        match connected {
+            Ok(true)  => Ok(()),
+            Ok(false) => { self.protocol = None; Err(SessionError::ConnectionFailed(reply)) },
+            Err(e)    => { self.protocol = None; Err(e) }
+        }
+    }
+
+    pub fn at(&mut self, command: AtCommand) -> Result<AtReply, SessionError> {
+        command.check()?;
+        let reply : String = self.transport.send(&command.command())?;
+        Ok(command.parse_reply(&reply)?)
+    }
+
+    pub fn send_raw(&mut self, request: &[u8]) -> Result<String, SessionError> {
+        if self.protocol.is_none() { return Err(SessionError::NotInitialized); }
+        let command : String = request.iter().map(|b| format!("{:02X}", b)).collect();
+        Ok(self.transport.send(&command)?)
+    }
+
+    pub fn query(&mut self, request: &[u8]) -> Result<Vec<Vec<u8>>, SessionError> {
+        //
+        // Méthode envoyant une requête OBD et retournant les messages de données reçus
+        //
+        Ok(self.query_ecus(request)?.into_iter().map(|r| r.data).collect())
+    }
+
+    pub fn query_ecus(&mut self, request: &[u8]) -> Result<Vec<EcuResponse>, SessionError> {
+        //
+        // Méthode envoyant une requête OBD et attribuant chaque message reçu à un calculateur
+        //
+        let command : String = request.iter().map(|b| format!("{:02X}", b)).collect();
+        let reply   : String = self.send_raw(request)?;
+        self.parse_ecus(&command, &reply)
+    }
+
+    fn parse_ecus(&self, command: &str, reply: &str) -> Result<Vec<EcuResponse>, SessionError> {
+        //
+        // Entêtes affichés, le calculateur est l'adresse source de la trame ; sinon le rang de la réponse
+        //
+        if self.headers {
+            let protocol : ObdProtocol = self.protocol().ok_or(SessionError::NotInitialized)?;
+            return Ok(parse_frames(protocol, command, reply)?);
+        }
+        Ok(parse_response(command, reply)?
+            .into_iter()
+            .enumerate()
+            .map(|(ecu, data)| EcuResponse { ecu: ecu as EcuAddress, data })
+            .collect())
+    }
+
+    pub fn read_pid<P: Pid>(&mut self, pid: &P) -> Result<Vec<P::Output>, SessionError> where P::Input: FromBytes {
+        //
+        // Méthode interrogeant le véhicule sur un pid et retournant la valeur décodée de chaque réponse
+        //
+        let messages : Vec<Vec<u8>> = self.query(&pid.request())?;
+        let mut values : Vec<P::Output> = vec![];
+        for message in messages.iter() {
+            values.push(pid.decode(message)?);
+        }
+        Ok(values)
+    }
+
+    pub fn read_value(&mut self, pid: &dyn DynPid) -> Result<Vec<DecodedValue>, SessionError> {
+        //
+        // Équivalent de read_pid pour un pid issu d'un PidRegistry
+        //
+        let messages : Vec<Vec<u8>> = self.query(&pid.request_bytes())?;
+        let mut values : Vec<DecodedValue> = vec![];
+        for message in messages.iter() {
+            values.push(pid.decode_value(message)?);
+        }
+        Ok(values)
+    }
+
+    pub fn read_values(&mut self, pids: &[&dyn DynPid]) -> Result<Vec<Vec<DecodedValue>>, SessionError> {
+        //
+        // Méthode lisant plusieurs pids du mode 0x01, regroupés par MAX_PIDS dans une même requête si le protocole l'autorise
+        // Les pids de longueur variable ou d'un autre mode sont interrogés séparément
+        // :return: Pour chaque pid, dans l'ordre demandé, la valeur décodée de chaque réponse
+        //
+        let protocol    : ObdProtocol             = self.protocol().ok_or(SessionError::NotInitialized)?;
+        let mut values  : Vec<Vec<DecodedValue>>  = vec![vec![]; pids.len()];
+        let mut grouped : Vec<(usize, usize)>     = vec![];
+        for (index, pid) in pids.iter().enumerate() {
+            let info : PidInfo = pid.info();
+            match info.result_size {
+                ResultSize::Value(size) if protocol.allows_multi_pid() && info.mode_number == 0x01 => grouped.push((index, size)),
+                _ => values[index] = self.read_value(*pid)?
+            }
+        }
+
+        for chunk in grouped.chunks(MAX_PIDS) {
+            let mut request : Vec<u8> = vec![0x01];
+            request.extend(chunk.iter().map(|(index, _)| pids[*index].info().pid_number as u8));
+            for message in self.query(&request)?.iter() {
+                // Réponse 41 suivie de chaque pid et de ses données, dans l'ordre choisi par le calculateur
+                let mode : u8 = *message.first().ok_or(PidError::Empty)?;
+                if mode != 0x41 { return Err(PidError::ModeMismatch { expected: 0x41, received: mode }.into()); }
+                let mut rest : &[u8] = &message[1..];
+                while let Some(pid_number) = rest.first().copied() {
+                    let (index, size) : (usize, usize) = *chunk.iter()
+                        .find(|(index, _)| pids[*index].info().pid_number as u8 == pid_number)
+                        .ok_or(PidError::Unsupported { mode: 0x01, pid: pid_number })?;
+                    ResultSize::Range(1 + size, usize::MAX).check(rest.len())?;
+                    let mut response : Vec<u8> = vec![0x41];
+                    response.extend_from_slice(&rest[..1 + size]);
+                    values[index].push(pids[index].decode_value(&response)?);
+                    rest = &rest[1 + size..];
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    pub fn read_freeze_frame<P: Pid>(&mut self, pid: P, frame: u8) -> Result<Vec<P::Output>, SessionError> where P::Input: FromBytes {
+        //
+        // Méthode lisant un pid du mode 0x01 dans la trame figée demandée (mode 0x02)
+        //
+        self.read_pid(&FreezeFrame::new(pid, frame))
+    }
+
+    pub fn read_freeze_frame_value(&mut self, pid: &dyn DynPid, frame: u8) -> Result<Vec<DecodedValue>, SessionError> {
+        //
+        // Équivalent de read_freeze_frame pour un pid du mode 0x01 issu d'un PidRegistry
+        //
+        let messages : Vec<Vec<u8>> = self.query(&[0x02, pid.info().pid_number as u8, frame])?;
+        let mut values : Vec<DecodedValue> = vec![];
+        for message in messages.iter() {
+            let (received, converted) = freeze_frame_to_mode01(message)?;
+            if received != frame { return Err(PidError::PidMismatch { expected: frame, received }.into()); }
+            values.push(pid.decode_value(&converted)?);
+        }
+        Ok(values)
+    }
+
+    pub fn supported_pids(&mut self) -> Result<SupportedPids, SessionError> {
+        self.supported(0x01)
+    }
+
+    fn supported(&mut self, mode: u8) -> Result<SupportedPids, SessionError> {
+        //
+        // Méthode interrogeant les pids 0x00, 0x20, 0x40 ... 0xc0 tant qu'un calculateur annonce la plage suivante
+        //
+        let mut supported : SupportedPids = SupportedPids::new();
+        let mut base      : PidLen        = 0x00;
+        loop {
+            let request : Vec<u8> = match mode {
+                0x08 => self.control_request(base as u8, &[])?,
+                _    => vec![mode, base as u8]
+            };
+            let responses : Vec<EcuResponse> = match self.query_ecus(&request) {
+                Ok(responses)                                       => responses,
+                Err(SessionError::Adapter(Elm327Error::NoData)) if base > 0 => break,
+                Err(e)                                              => return Err(e)
+            };
+            for response in responses.iter() {
+                let data : &[u8] = check_response(&[0x40 + mode, base as u8], &response.data)?;
+                // Hors CAN, la réponse du mode 0x08 conserve la longueur fixe de la requête
+                let size : ResultSize = match mode {
+                    0x08 if !self.is_can() => ResultSize::Range(0x04, self.control_data_len()?),
+                    _                      => ResultSize::Value(0x04)
+                };
+                size.check(data.len())?;
+                supported.insert_bitmask(response.ecu, base, u32::from_bytes(data));
+            }
+            if base >= 0xc0 || !supported.contains(base + 0x20) { break; }
+            base += 0x20;
+        }
+        Ok(supported)
+    }
+
+    pub fn read_stored_dtcs(&mut self) -> Result<BTreeMap<EcuAddress, Vec<Dtc>>, SessionError> {
+        //
+        // Méthode lisant les codes défaut mémorisés (mode 0x03) de chaque calculateur
+        //
+        self.read_dtcs(DtcCategory::Stored.mode())
+    }
+
+    pub fn read_pending_dtcs(&mut self) -> Result<BTreeMap<EcuAddress, Vec<Dtc>>, SessionError> {
+        self.read_dtcs(DtcCategory::Pending.mode())
+    }
+
+    pub fn read_permanent_dtcs(&mut self) -> Result<BTreeMap<EcuAddress, Vec<Dtc>>, SessionError> {
+        self.read_dtcs(DtcCategory::Permanent.mode())
+    }
+
+    pub fn read_all_dtcs(&mut self) -> Result<Vec<ReportedDtc>, SessionError> {
+        //
+        // Méthode lisant les codes mémorisés, en attente et permanents
+        // Le mode 0x0a n'existant que sur les véhicules récents, une réponse négative y est considérée comme une absence de code
+        //
+        let mut reported : Vec<ReportedDtc> = vec![];
+        for category in DtcCategory::all().iter() {
+            let dtcs : BTreeMap<EcuAddress, Vec<Dtc>> = match self.read_dtcs(category.mode()) {
+                Ok(dtcs) => dtcs,
+                Err(SessionError::Pid(PidError::NegativeResponse { .. })) if *category == DtcCategory::Permanent => continue,
+                Err(e)   => return Err(e)
+            };
+            for (ecu, dtcs) in dtcs.into_iter() {
+                reported.extend(dtcs.into_iter().map(|dtc| ReportedDtc { ecu, category: *category, dtc }));
+            }
+        }
+        Ok(reported)
+    }
+
+    fn read_dtcs(&mut self, mode: u8) -> Result<BTreeMap<EcuAddress, Vec<Dtc>>, SessionError> {
+        let can : bool = self.is_can();
+        let mut dtcs : BTreeMap<EcuAddress, Vec<Dtc>> = BTreeMap::new();
+        for (ecu, messages) in self.messages_by_ecu(&[mode])?.into_iter() {
+            let entry : &mut Vec<Dtc> = dtcs.entry(ecu).or_default();
+            for message in messages.iter() { entry.extend(decode_dtcs(mode, message, can)?); }
+        }
+        Ok(dtcs)
+    }
+
+    fn messages_by_ecu(&mut self, request: &[u8]) -> Result<BTreeMap<EcuAddress, Vec<Vec<u8>>>, SessionError> {
+        //
+        // Regroupe les messages par calculateur, NO DATA donnant un résultat vide
+        // Hors CAN et sans entêtes, un calculateur répond sur plusieurs lignes qu'il est impossible d'attribuer :
+        // elles sont alors regroupées sous le calculateur 0
+        //
+        let grouped : bool = self.is_can() || self.headers;
+        let responses : Vec<EcuResponse> = match self.query_ecus(request) {
+            Ok(responses)                                   => responses,
+            Err(SessionError::Adapter(Elm327Error::NoData)) => return Ok(BTreeMap::new()),
+            Err(e)                                          => return Err(e)
+        };
+        let mut messages : BTreeMap<EcuAddress, Vec<Vec<u8>>> = BTreeMap::new();
+        for response in responses.into_iter() {
+            let ecu : EcuAddress = if grouped { response.ecu } else { 0 };
+            messages.entry(ecu).or_default().push(response.data);
+        }
+        Ok(messages)
+    }
+
+    pub fn clear_dtcs(&mut self, confirmation: ClearConfirmation) -> Result<Vec<EcuAddress>, SessionError> {
+        //
+        // Méthode effaçant les codes défaut et les données associées (mode 0x04)
+        // Refuse moteur tournant ou régime inconnu (NO DATA), sauf si la confirmation l'autorise
+        // Les entêtes sont affichés le temps de la requête : une réponse 0x78 (réponse en attente) doit être suivie
+        // de la réponse finale du même calculateur, reconnu par son adresse
+        // :return: Adresses des calculateurs ayant acquitté l'effacement
+        //
+        if !confirmation.allow_engine_running {
+            let values : Vec<f64> = match self.read_pid(&EngineSpeed::new()) {
+                Ok(values)                                      => values,
+                Err(SessionError::Adapter(Elm327Error::NoData)) => vec![],
+                Err(e)                                          => return Err(e)
+            };
+            if values.is_empty() { return Err(SessionError::EngineSpeedUnavailable); }
+            let rpm : f64 = values.into_iter().fold(0.0, f64::max);
+            if rpm > 0.0 { return Err(SessionError::EngineRunning(rpm)); }
+        }
+
+        let headers : bool = self.headers;
+        if !headers { self.at(AtCommand::Headers(true))?; }
+        self.headers = true;
+        let responses : Result<Vec<EcuResponse>, SessionError> = self.query_ecus(&[0x04]);
+        self.headers = headers;
+        if !headers { self.at(AtCommand::Headers(false))?; }
+
+        let mut acknowledged : Vec<EcuAddress>                         = vec![];
+        let mut rejected     : Vec<(EcuAddress, NegativeResponseCode)> = vec![];
+        let mut pending      : Vec<EcuAddress>                         = vec![];
+        for response in responses?.iter() {
+            match check_response(&[0x44], &response.data) {
+                Ok(_) => acknowledged.push(response.ecu),
+                Err(PidError::NegativeResponse { code, .. }) => {
+                    let code : NegativeResponseCode = NegativeResponseCode::from_code(code);
+                    if code == NegativeResponseCode::ResponsePending { pending.push(response.ecu); continue; }
+                    rejected.push((response.ecu, code));
+                },
+                Err(e) => return Err(e.into())
+            }
+            if let Some(index) = pending.iter().position(|ecu| *ecu == response.ecu) {
+                pending.remove(index);
+            }
+        }
+        if !rejected.is_empty() { return Err(SessionError::ClearRejected { acknowledged, rejected }); }
+        if !pending.is_empty() { return Err(SessionError::ClearPending { acknowledged, pending }); }
+        Ok(acknowledged)
+    }
+
+    pub fn read_info(&mut self, info: InfoType) -> Result<BTreeMap<EcuAddress, Vec<u8>>, SessionError> {
+        //
+        // Méthode lisant une information du mode 0x09 et retournant ses données brutes par calculateur
+        //
+        let can : bool = self.is_can();
+        let mut payloads : BTreeMap<EcuAddress, Vec<u8>> = BTreeMap::new();
+        for (ecu, messages) in self.messages_by_ecu(&[0x09, info.number()])?.into_iter() {
+            payloads.insert(ecu, decode_info_payload(info, &messages, can)?);
+        }
+        Ok(payloads)
+    }
+
+    pub fn supported_info_types(&mut self) -> Result<SupportedPids, SessionError> {
+        let mut supported : SupportedPids = SupportedPids::new();
+        for (ecu, data) in self.read_info(InfoType::SupportedInfoTypes)?.into_iter() {
+            ResultSize::Value(0x04).check(data.len())?;
+            supported.insert_bitmask(ecu, 0x00, u32::from_bytes(&data));
+        }
+        Ok(supported)
+    }
+
+    pub fn read_message_counts(&mut self, info: InfoType) -> Result<BTreeMap<EcuAddress, u8>, SessionError> {
+        //
+        // Méthode lisant, hors CAN, le nombre de messages composant une information (VIN, CAL ID, ...)
+        // Les informations sans type de comptage associé (numéro de série moteur, ...) sont refusées
+        //
+        let count : InfoType = info.message_count().ok_or(PidError::Unsupported { mode: 0x09, pid: info.number() })?;
+        let mut counts : BTreeMap<EcuAddress, u8> = BTreeMap::new();
+        for (ecu, data) in self.read_info(count)?.into_iter() {
+            counts.insert(ecu, *data.first().ok_or(PidError::TooShort { expected: 1, received: data.len() })?);
+        }
+        Ok(counts)
+    }
+
+    pub fn read_vin(&mut self) -> Result<Option<String>, SessionError> {
+        //
+        // Méthode lisant le VIN, annoncé en général par le seul calculateur moteur
+        //
+        match self.read_info(InfoType::Vin)?.values().next() {
+            Some(data) => Ok(Some(decode_vin(data)?)),
+            None       => Ok(None)
+        }
+    }
+
+    pub fn read_calibration_ids(&mut self) -> Result<BTreeMap<EcuAddress, Vec<String>>, SessionError> {
+        Ok(self.read_info(InfoType::CalibrationId)?.into_iter().map(|(ecu, data)| (ecu, decode_calibration_ids(&data))).collect())
+    }
+
+    pub fn read_cvns(&mut self) -> Result<BTreeMap<EcuAddress, Vec<u32>>, SessionError> {
+        Ok(self.read_info(InfoType::Cvn)?.into_iter().map(|(ecu, data)| (ecu, decode_cvns(&data))).collect())
+    }
+
+    pub fn read_performance_counters(&mut self, ignition: IgnitionType) -> Result<BTreeMap<EcuAddress, Vec<u16>>, SessionError> {
+        //
+        // Méthode lisant les compteurs bruts du suivi des performances en utilisation (types 0x08 et 0x0b)
+        //
+        Ok(self.read_info(performance_tracking_info(ignition))?.into_iter().map(|(ecu, data)| (ecu, decode_counters(&data))).collect())
+    }
+
+    pub fn read_performance_tracking(&mut self, ignition: IgnitionType) -> Result<BTreeMap<EcuAddress, PerformanceTracking>, SessionError> {
+        let mut tracking : BTreeMap<EcuAddress, PerformanceTracking> = BTreeMap::new();
+        for (ecu, data) in self.read_info(performance_tracking_info(ignition))?.into_iter() {
+            tracking.insert(ecu, decode_performance_tracking(ignition, &data)?);
+        }
+        Ok(tracking)
+    }
+
+    pub fn read_ecu_names(&mut self) -> Result<BTreeMap<EcuAddress, EcuName>, SessionError> {
+        Ok(self.read_info(InfoType::EcuName)?.into_iter().map(|(ecu, data)| (ecu, decode_ecu_name(&data))).collect())
+    }
+
+    pub fn read_engine_serial_number(&mut self) -> Result<BTreeMap<EcuAddress, String>, SessionError> {
+        Ok(self.read_info(InfoType::EngineSerialNumber)?.into_iter().map(|(ecu, data)| (ecu, decode_ascii(&data))).collect())
+    }
+
+    pub fn read_type_approval_number(&mut self) -> Result<BTreeMap<EcuAddress, String>, SessionError> {
+        Ok(self.read_info(InfoType::TypeApprovalNumber)?.into_iter().map(|(ecu, data)| (ecu, decode_ascii(&data))).collect())
+    }
+
+    pub fn supported_monitor_ids(&mut self) -> Result<SupportedPids, SessionError> {
+        //
+        // Méthode listant les OBDMID supportés (mode 0x06), uniquement sur CAN
+        //
+        if !self.is_can() { return Err(SessionError::UnsupportedMode(0x06)); }
+        self.supported(0x06)
+    }
+
+    pub fn read_monitor_tests(&mut self, obdmid: u8) -> Result<BTreeMap<EcuAddress, Vec<MonitorTestResult>>, SessionError> {
+        //
+        // Méthode lisant les résultats des tests d'un moniteur (mode 0x06), uniquement sur CAN
+        //
+        if !self.is_can() { return Err(SessionError::UnsupportedMode(0x06)); }
+        let mut results : BTreeMap<EcuAddress, Vec<MonitorTestResult>> = BTreeMap::new();
+        for (ecu, messages) in self.messages_by_ecu(&[0x06, obdmid])?.into_iter() {
+            let entry : &mut Vec<MonitorTestResult> = results.entry(ecu).or_default();
+            for message in messages.iter() { entry.extend(decode_monitor_tests(message)?); }
+        }
+        Ok(results)
+    }
+
+    pub fn read_all_monitor_tests(&mut self) -> Result<BTreeMap<EcuAddress, Vec<MonitorTestResult>>, SessionError> {
+        //
+        // Méthode lisant les résultats de tous les OBDMID annoncés par au moins un calculateur
+        //
+        let supported : SupportedPids = self.supported_monitor_ids()?;
+        let mut results : BTreeMap<EcuAddress, Vec<MonitorTestResult>> = BTreeMap::new();
+        for obdmid in supported.iter().filter(|obdmid| obdmid % 0x20 != 0) {
+            for (ecu, tests) in self.read_monitor_tests(obdmid as u8)?.into_iter() {
+                results.entry(ecu).or_default().extend(tests);
+            }
+        }
+        Ok(results)
+    }
+
+    pub fn supported_oxygen_sensor_tests(&mut self) -> Result<SupportedPids, SessionError> {
+        //
+        // Méthode listant les tests de capteurs d'oxygène supportés (mode 0x05), absent sur CAN où le mode 0x06 le remplace
+        //
+        if self.is_can() { return Err(SessionError::UnsupportedMode(0x05)); }
+        self.supported(0x05)
+    }
+
+    pub fn read_oxygen_sensor_test(&mut self, test: OxygenSensorTest, sensor: u8) -> Result<BTreeMap<EcuAddress, OxygenSensorTestResult>, SessionError> {
+        //
+        // Méthode lisant un test du capteur d'oxygène numéro sensor (1 à 8, comme OxygenSensor1 à OxygenSensor8)
+        //
+        if self.is_can() { return Err(SessionError::UnsupportedMode(0x05)); }
+        let mut results : BTreeMap<EcuAddress, OxygenSensorTestResult> = BTreeMap::new();
+        for response in self.query_ecus(&[0x05, test.tid(), sensor])?.iter() {
+            results.insert(response.ecu, decode_oxygen_sensor_test(&response.data)?);
+        }
+        Ok(results)
+    }
+
+    pub fn read_oxygen_sensor_tests(&mut self, sensor: u8) -> Result<Vec<(EcuAddress, OxygenSensorTestResult)>, SessionError> {
+        //
+        // Méthode lisant tous les tests annoncés pour un capteur d'oxygène
+        //
+        let supported : SupportedPids = self.supported_oxygen_sensor_tests()?;
+        let mut results : Vec<(EcuAddress, OxygenSensorTestResult)> = vec![];
+        for tid in supported.iter().filter(|tid| tid % 0x20 != 0) {
+            match self.read_oxygen_sensor_test(OxygenSensorTest::from_tid(tid as u8), sensor) {
+                Ok(tests)                                       => results.extend(tests),
+                Err(SessionError::Adapter(Elm327Error::NoData)) => continue,
+                Err(e)                                          => return Err(e)
+            }
+        }
+        Ok(results)
+    }
+
+    pub fn supported_control_tests(&mut self) -> Result<SupportedPids, SessionError> {
+        //
+        // Méthode listant les tests de contrôle supportés (mode 0x08), sans rien déclencher
+        //
+        self.supported(0x08)
+    }
+
+    pub fn run_control_test(&mut self, tid: u8, data: &[u8], _: ControlConfirmation) -> Result<BTreeMap<EcuAddress, ControlOutcome>, SessionError> {
+        //
+        // Méthode déclenchant un test de contrôle embarqué (mode 0x08)
+        //
+        if tid % 0x20 == 0 { return Err(PidError::Unsupported { mode: 0x08, pid: tid }.into()); }
+        let request : Vec<u8> = self.control_request(tid, data)?;
+
+        let mut outcomes : BTreeMap<EcuAddress, ControlOutcome> = BTreeMap::new();
+        for response in self.query_ecus(&request)?.into_iter() {
+            match check_response(&[0x48, tid], &response.data) {
+                Ok(data) => { outcomes.insert(response.ecu, ControlOutcome::Accepted(data.to_vec())); },
+                Err(PidError::NegativeResponse { code, .. }) => {
+                    let code : NegativeResponseCode = NegativeResponseCode::from_code(code);
+                    if code != NegativeResponseCode::ResponsePending { outcomes.insert(response.ecu, ControlOutcome::Rejected(code)); }
+                },
+                Err(e) => return Err(e.into())
+            }
+        }
+        Ok(outcomes)
+    }
+
+    fn control_data_len(&self) -> Result<usize, SessionError> {
+        //
+        // Octets de données d'une requête du mode 0x08 tenant dans une trame, mode et TID exclus
+        //
+        let protocol : ObdProtocol = self.protocol().ok_or(SessionError::NotInitialized)?;
+        Ok(protocol.max_data_bytes().ok_or(SessionError::NotInitialized)? - 2)
+    }
+
+    fn control_request(&self, tid: u8, data: &[u8]) -> Result<Vec<u8>, SessionError> {
+        //
+        // Requête du mode 0x08 : hors CAN elle occupe toujours la trame entière, complétée par des 0x00,
+        // des données plus longues étant refusées plutôt que tronquées
+        //
+        let maximum : usize = self.control_data_len()?;
+        if data.len() > maximum {
+            return Err(SessionError::RequestTooLong { maximum, received: data.len() });
+        }
+        let mut request : Vec<u8> = vec![0x08, tid];
+        request.extend_from_slice(data);
+        if !self.is_can() { request.resize(2 + maximum, 0x00); }
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elm327::decoder::decode_percent;
+    use std::collections::HashMap;
+
+    struct ScriptedAdapter {
+        //
+        // Adaptateur simulé : réponse fixe par commande, journal des commandes reçues
+        //
+        replies:  HashMap<String, String>,
+        commands: Vec<String>,
+        timeout:  Duration
+    }
+
+    impl Transport for ScriptedAdapter {
+        fn write_command(&mut self, command: &str) -> Result<(), TransportError> {
+            self.commands.push(command.to_string());
+            Ok(())
+        }
+
+        fn read_until_prompt(&mut self) -> Result<String, TransportError> {
+            let command : &String = self.commands.last().ok_or(TransportError::Closed)?;
+            self.replies.get(command).cloned().ok_or(TransportError::Timeout)
+        }
+
+        fn timeout(&self) -> Duration { self.timeout }
+        fn set_timeout(&mut self, timeout: Duration) { self.timeout = timeout; }
+    }
+
+    fn connect(protocol: &str, replies: &[(&str, &str)]) -> Elm327<ScriptedAdapter> {
+        let mut script : HashMap<String, String> = [
+            ("ATZ", "ELM327 v1.5\r\r"), ("ATE0", "OK\r\r"), ("ATL0", "OK\r\r"), ("ATH0", "OK\r\r"), ("ATSP0", "OK\r\r"),
+            ("0100", "SEARCHING...\r41 00 BE 1F A8 13\r\r"), ("ATDPN", protocol)
+        ].iter().map(|(c, r)| (c.to_string(), r.to_string())).collect();
+        script.extend(replies.iter().map(|(c, r)| (c.to_string(), r.to_string())));
+        Elm327::connect(ScriptedAdapter { replies: script, commands: vec![], timeout: DEFAULT_TIMEOUT }).unwrap()
+    }
+
+    fn last_command(session: &mut Elm327<ScriptedAdapter>) -> String {
+        session.transport().commands.last().cloned().unwrap_or_default()
+    }
+
+    #[test]
+    fn control_support_query_is_padded_outside_can() {
+        let mut session = connect("A3\r\r", &[("08000000000000", "48 00 C0 00 00 00 00\r\r")]);
+        let supported : SupportedPids = session.supported_control_tests().unwrap();
+        assert!(supported.contains(0x01) && supported.contains(0x02) && !supported.contains(0x03));
+        assert_eq!(last_command(&mut session), "08000000000000");
+
+        let mut session = connect("A6\r\r", &[("0800", "48 00 80 00 00 00\r\r")]);
+        assert!(session.supported_control_tests().unwrap().contains(0x01));
+    }
+
+    #[test]
+    fn multiple_pids_share_a_request_on_can_only() {
+        let engine_speed  : EngineSpeed  = EngineSpeed::new();
+        let vehicle_speed : VehicleSpeed = VehicleSpeed::new();
+        let pids : [&dyn DynPid; 2] = [&engine_speed, &vehicle_speed];
+        let expected : Vec<Vec<DecodedValue>> = vec![
+            vec![DecodedValue::Scalar(1726.0, Some("rpm"))],
+            vec![DecodedValue::Scalar(50.0, Some("km/h"))]
+        ];
+
+        let mut session = connect("A6\r\r", &[("010C0D", "41 0D 32 0C 1A F8\r\r")]);
+        assert_eq!(session.read_values(&pids).unwrap(), expected);
+
+        let mut session = connect("A3\r\r", &[("010C", "41 0C 1A F8\r\r"), ("010D", "41 0D 32\r\r")]);
+        assert_eq!(session.read_values(&pids).unwrap(), expected);
+        assert_eq!(last_command(&mut session), "010D");
+    }
+
+    #[test]
+    fn supported_pids_follow_the_announced_ranges() {
+        //
+        // 0x20 annoncé par le seul calculateur 0 : la plage 0x21-0x40 est demandée, 0x40 n'y étant pas annoncé la recherche s'arrête
+        //
+        let mut session = connect("A6\r\r", &[
+            ("0100", "41 00 BE 1F A8 13\r41 00 80 00 00 00\r\r"),
+            ("0120", "41 20 80 01 80 00\r\r")
+        ]);
+        let supported : SupportedPids = session.supported_pids().unwrap();
+        assert_eq!(last_command(&mut session), "0120");
+        assert!(supported.contains_for(0, 0x21) && supported.contains_for(0, 0x30) && supported.contains_for(0, 0x31));
+        assert!(supported.contains_for(1, 0x01) && !supported.contains_for(1, 0x21) && !supported.contains(0x40));
+
+        let mut session = connect("A6\r\r", &[("0100", "41 00 00 00 00 01\r\r"), ("0120", "NO DATA\r\r")]);
+        assert_eq!(session.supported_pids().unwrap().iter().collect::<Vec<PidLen>>(), vec![0x20]);
+        assert!(matches!(connect("A6\r\r", &[("0120", "41 20 80\r\r"), ("0100", "41 00 00 00 00 01\r\r")]).supported_pids(),
+            Err(SessionError::Pid(PidError::TooShort { expected: 4, received: 1 }))));
+    }
+
+    #[test]
+    fn multi_pid_reply_is_split_per_pid() {
+        //
+        // 7 pids : 6 dans une première requête, à laquelle 2 calculateurs répondent dans leur propre ordre, puis 1
+        //
+        let (load, coolant, rpm, speed, intake, maf, throttle) = (EngineLoad::new(), EngineCoolantTemperature::new(),
+            EngineSpeed::new(), VehicleSpeed::new(), IntakeAirTemperature::new(), MAFSensor::new(), ThrottlePosition::new());
+        let pids : [&dyn DynPid; 7] = [&load, &coolant, &rpm, &speed, &intake, &maf, &throttle];
+        let mut session = connect("A6\r\r", &[
+            ("0104050C0D0F10", "00F\r0: 41 04 80 05 5A 0C\r1: 1A F8 0D 32 0F 3C 10\r2: 01 F4 00 00 00 00 00\r41 0D 00 04 00\r\r"),
+            ("0111", "41 11 33\r\r")
+        ]);
+        let values : Vec<Vec<DecodedValue>> = session.read_values(&pids).unwrap();
+        assert_eq!(last_command(&mut session), "0111");
+        assert_eq!(values[0], vec![DecodedValue::Scalar(decode_percent(0x80), Some("%")), DecodedValue::Scalar(0.0, Some("%"))]);
+        assert_eq!(values[1], vec![DecodedValue::Scalar(50.0, Some("°C"))]);
+        assert_eq!(values[2], vec![DecodedValue::Scalar(1726.0, Some("rpm"))]);
+        assert_eq!(values[3], vec![DecodedValue::Scalar(50.0, Some("km/h")), DecodedValue::Scalar(0.0, Some("km/h"))]);
+        assert_eq!(values[4], vec![DecodedValue::Scalar(20.0, Some("°C"))]);
+        assert_eq!(values[5], vec![DecodedValue::Scalar(125.0, Some("grams/sec"))]);
+        assert_eq!(values[6].len(), 1);
+
+        let mut session = connect("A6\r\r", &[("010C0D", "41 0C 1A F8 0D\r\r")]);
+        assert!(matches!(session.read_values(&pids[2..4]), Err(SessionError::Pid(PidError::TooShort { expected: 2, received: 1 }))));
+        let mut session = connect("A6\r\r", &[("010C0D", "41 0C 1A F8 11 33\r\r")]);
+        assert!(matches!(session.read_values(&pids[2..4]), Err(SessionError::Pid(PidError::Unsupported { mode: 0x01, pid: 0x11 }))));
+    }
+
+    #[test]
+    fn pids_are_read_one_by_one_without_multi_pid_support() {
+        let rpm   : EngineSpeed  = EngineSpeed::new();
+        let speed : VehicleSpeed = VehicleSpeed::new();
+        let pids : [&dyn DynPid; 2] = [&rpm, &speed];
+        for protocol in ["AA\r\r", "AB\r\r", "A1\r\r"].iter() {
+            let mut session = connect(protocol, &[("010C", "41 0C 1A F8\r\r"), ("010D", "41 0D 32\r\r")]);
+            assert_eq!(session.read_values(&pids).unwrap().iter().map(|v| v.len()).collect::<Vec<usize>>(), vec![1, 1]);
+            let commands : &Vec<String> = &session.transport().commands;
+            assert_eq!(&commands[commands.len() - 2..], &["010C".to_string(), "010D".to_string()]);
+        }
+    }
+
+    #[test]
+    fn message_counts_need_a_count_info_type() {
+        let mut session = connect("A3\r\r", &[("0901", "49 01 05\r\r")]);
+        assert_eq!(session.read_message_counts(InfoType::Vin).unwrap(), [(0, 5)].iter().copied().collect());
+        assert!(matches!(session.read_message_counts(InfoType::EngineSerialNumber),
+            Err(SessionError::Pid(PidError::Unsupported { mode: 0x09, pid: 0x0d }))));
+        assert_eq!(last_command(&mut session), "0901");
+    }
+
+    #[test]
+    fn all_dtcs_are_labelled_with_their_category() {
+        //
+        // Mode 0x03 sur plusieurs trames ISO-TP, mode 0x07 sur une trame et mode 0x0a refusé (véhicule ancien)
+        //
+        let mut session = connect("A6\r\r", &[
+            ("03", "00A\r0: 43 04 01 33 01 34\r1: 01 35 C1 00 00 00 00\r\r"),
+            ("07", "47 01 01 36\r\r"),
+            ("0A", "7F 0A 11\r\r")
+        ]);
+        let reported : Vec<ReportedDtc> = session.read_all_dtcs().unwrap();
+        let labels : Vec<String> = reported.iter().map(|r| format!("{} {:?} {}", r.ecu, r.category, r.dtc)).collect();
+        assert_eq!(labels, vec!["0 Stored P0133", "0 Stored P0134", "0 Stored P0135", "0 Stored U0100", "0 Pending P0136"]);
+        assert_eq!(last_command(&mut session), "0A");
+
+        let mut session = connect("A6\r\r", &[("07", "NO DATA\r\r"), ("0A", "4A 01 01 36\r\r")]);
+        assert_eq!(session.read_pending_dtcs().unwrap(), BTreeMap::new());
+        assert_eq!(session.read_permanent_dtcs().unwrap()[&0], vec!["P0136".parse::<Dtc>().unwrap()]);
+    }
+
+    #[test]
+    fn clear_waits_for_final_response() {
+        //
+        // Les entêtes sont activés le temps de l'effacement : chaque réponse finale est attribuée par l'adresse de son calculateur
+        //
+        let stopped : (&str, &str) = ("010C", "41 0C 00 00\r\r");
+        let mut session = connect("A6\r\r", &[stopped, ("ATH1", "OK\r\r"), ("04", "7E8 03 7F 04 78\r7E8 01 44\r\r")]);
+        assert_eq!(session.clear_dtcs(ClearConfirmation::confirmed()).unwrap(), vec![0x7e8]);
+        assert_eq!(last_command(&mut session), "ATH0");
+        assert!(!session.headers());
+
+        let mut session = connect("A6\r\r", &[stopped, ("ATH1", "OK\r\r"), ("04", "7E8 03 7F 04 78\r7E9 01 44\r\r")]);
+        assert!(matches!(session.clear_dtcs(ClearConfirmation::confirmed()),
+            Err(SessionError::ClearPending { ref acknowledged, ref pending }) if *acknowledged == vec![0x7e9] && *pending == vec![0x7e8]));
+
+        let mut session = connect("A6\r\r", &[("010C", "41 0C 0B B8\r\r")]);
+        assert!(matches!(session.clear_dtcs(ClearConfirmation::confirmed()), Err(SessionError::EngineRunning(_))));
+        assert_eq!(last_command(&mut session), "010C");
+    }
+
+    #[test]
+    fn clear_needs_a_known_engine_speed() {
+        let mut session = connect("A6\r\r", &[("010C", "NO DATA\r\r")]);
+        assert!(matches!(session.clear_dtcs(ClearConfirmation::confirmed()), Err(SessionError::EngineSpeedUnavailable)));
+        assert_eq!(last_command(&mut session), "010C");
+
+        let mut session = connect("A6\r\r", &[("ATH1", "OK\r\r"), ("04", "7E8 01 44\r\r")]);
+        assert_eq!(session.clear_dtcs(ClearConfirmation::confirmed().allow_engine_running()).unwrap(), vec![0x7e8]);
+    }
+
+    #[test]
+    fn control_data_is_never_truncated() {
+        let mut session = connect("A3\r\r", &[]);
+        let result = session.run_control_test(EVAP_LEAK_TEST, &[1, 2, 3, 4, 5, 6], ControlConfirmation::confirmed());
+        assert!(matches!(result, Err(SessionError::RequestTooLong { maximum: 5, received: 6 })));
+        assert_eq!(last_command(&mut session), "ATDPN");
+
+        // Les TID multiples de 0x20 sont des requêtes de support, pas des tests
+        for tid in [0x00, 0x20, 0xe0].iter() {
+            assert!(matches!(session.run_control_test(*tid, &[], ControlConfirmation::confirmed()),
+                Err(SessionError::Pid(PidError::Unsupported { mode: 0x08, pid })) if pid == *tid));
+        }
+        assert_eq!(last_command(&mut session), "ATDPN");
+    }
+}
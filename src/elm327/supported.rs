@@ -0,0 +1,166 @@
+// Uses
+use std::collections::BTreeMap;
+use crate::elm327::decoder::OxygenSensorLayout;
+use crate::elm327::types::*;
+
+// Structs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PidSet {
+    //
+    // Ensemble de pids 0x00 à 0xff stocké sous forme de bitmap
+    //
+    bits: [u64; 4]
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SupportedPids {
+    //
+    // Pids supportés par le véhicule
+    // :field merged: Union des pids annoncés par tous les calculateurs
+    // :field ecus:   Pids annoncés par chaque calculateur
+    //
+    merged: PidSet,
+    ecus:   BTreeMap<EcuAddress, PidSet>
+}
+
+impl PidSet {
+    pub fn new() -> Self {
+        PidSet { bits: [0; 4] }
+    }
+
+    pub fn insert(&mut self, pid: PidLen) {
+        if pid < 0x100 { self.bits[pid / 64] |= 1 << (pid % 64); }
+    }
+
+    pub fn contains(&self, pid: PidLen) -> bool {
+        pid < 0x100 && self.bits[pid / 64] & (1 << (pid % 64)) != 0
+    }
+
+    pub fn union(&mut self, other: &PidSet) {
+        for (bits, other) in self.bits.iter_mut().zip(other.bits.iter()) { *bits |= *other; }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|b| *b == 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = PidLen> + '_ {
+        (0..0x100).filter(move |pid| self.contains(*pid))
+    }
+}
+
+impl SupportedPids {
+    pub fn new() -> Self {
+        SupportedPids { merged: PidSet::new(), ecus: BTreeMap::new() }
+    }
+
+    pub fn insert(&mut self, ecu: EcuAddress, pid: PidLen) {
+        self.merged.insert(pid);
+        self.ecus.entry(ecu).or_default().insert(pid);
+    }
+
+    pub fn insert_bitmask(&mut self, ecu: EcuAddress, base: PidLen, bitmask: u32) {
+        //
+        // Ajoute les pids annoncés par la réponse au pid base (0x00, 0x20, ...), le bit 31 désignant base+1
+        //
+        let set : &mut PidSet = self.ecus.entry(ecu).or_default();
+        for offset in 0..32 {
+            if bitmask & (0x8000_0000 >> offset) != 0 {
+                set.insert(base + offset + 1);
+                self.merged.insert(base + offset + 1);
+            }
+        }
+    }
+
+    pub fn contains(&self, pid: PidLen) -> bool {
+        self.merged.contains(pid)
+    }
+
+    pub fn contains_for(&self, ecu: EcuAddress, pid: PidLen) -> bool {
+        self.ecus.get(&ecu).is_some_and(|set| set.contains(pid))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = PidLen> + '_ {
+        self.merged.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.merged.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.merged.is_empty()
+    }
+
+    pub fn ecus(&self) -> impl Iterator<Item = EcuAddress> + '_ {
+        self.ecus.keys().copied()
+    }
+
+    pub fn for_ecu(&self, ecu: EcuAddress) -> Option<&PidSet> {
+        self.ecus.get(&ecu)
+    }
+
+    pub fn oxygen_sensor_layout(&self) -> OxygenSensorLayout {
+        //
+        // Un véhicule annonce soit le pid 0x13 soit le pid 0x1d, ce dernier désignant 4 banques
+        //
+        if self.contains(0x1d) { OxygenSensorLayout::FourBanks } else { OxygenSensorLayout::TwoBanks }
+    }
+
+    pub fn merge(&mut self, other: &SupportedPids) {
+        self.merged.union(&other.merged);
+        for (ecu, set) in other.ecus.iter() {
+            self.ecus.entry(*ecu).or_default().union(set);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitmask_msb_is_base_plus_one() {
+        let mut supported : SupportedPids = SupportedPids::new();
+        supported.insert_bitmask(0x7e8, 0x00, 0xbe1f_a813);
+        assert_eq!(supported.iter().collect::<Vec<PidLen>>(),
+            vec![0x01, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x13, 0x15, 0x1c, 0x1f, 0x20]);
+        assert!(!supported.contains(0x00) && !supported.contains(0x21));
+
+        supported.insert_bitmask(0x7e8, 0x20, 0x8000_0001);
+        // Le dernier bit de la plage 0xe0 désignerait le pid 0x100, qui n'existe pas
+        supported.insert_bitmask(0x7e8, 0xe0, 0x0000_0003);
+        assert!(supported.contains(0x21) && supported.contains(0x40) && !supported.contains(0x3f));
+        assert!(supported.contains(0xff) && supported.for_ecu(0x7e8).unwrap().contains(0xff));
+        assert_eq!(supported.len(), 20);
+    }
+
+    #[test]
+    fn pids_are_kept_per_ecu() {
+        let mut engine : SupportedPids = SupportedPids::new();
+        engine.insert_bitmask(0x7e8, 0x00, 0x0000_0001);
+        let mut transmission : SupportedPids = SupportedPids::new();
+        transmission.insert_bitmask(0x7e9, 0x00, 0x8000_0000);
+        transmission.insert_bitmask(0x7e8, 0x20, 0x4000_0000);
+
+        engine.merge(&transmission);
+        assert_eq!(engine.ecus().collect::<Vec<EcuAddress>>(), vec![0x7e8, 0x7e9]);
+        assert!(engine.contains_for(0x7e8, 0x20) && engine.contains_for(0x7e8, 0x22) && !engine.contains_for(0x7e8, 0x01));
+        assert!(engine.contains_for(0x7e9, 0x01) && !engine.contains_for(0x7e9, 0x20) && !engine.contains_for(0x7ea, 0x01));
+        assert_eq!(engine.iter().collect::<Vec<PidLen>>(), vec![0x01, 0x20, 0x22]);
+    }
+
+    #[test]
+    fn oxygen_sensor_layout_follows_pid_1d() {
+        let mut supported : SupportedPids = SupportedPids::new();
+        supported.insert(0, 0x13);
+        assert_eq!(supported.oxygen_sensor_layout(), OxygenSensorLayout::TwoBanks);
+        supported.insert(1, 0x1d);
+        assert_eq!(supported.oxygen_sensor_layout(), OxygenSensorLayout::FourBanks);
+        assert_eq!(SupportedPids::new().oxygen_sensor_layout(), OxygenSensorLayout::TwoBanks);
+    }
+}
@@ -0,0 +1,137 @@
+// Uses
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+use crate::elm327::transport::*;
+
+// Constantes
+pub const DEFAULT_ADDRESS         : &str     = "192.168.0.10:35000";
+pub const DEFAULT_CONNECT_TIMEOUT : Duration = Duration::from_secs(3);
+
+// Structs
+pub struct TcpTransport {
+    //
+    // Transport vers un elm327 wifi exposé sur un socket tcp
+    // :field stream:  Socket connecté à l'adaptateur
+    // :field timeout: Délai maximal d'attente du prompt
+    // :field pending: Octets reçus et pas encore rendus, dont ceux arrivés après le dernier prompt
+    //
+    stream:  TcpStream,
+    timeout: Duration,
+    pending: Vec<u8>
+}
+
+impl TcpTransport {
+    pub fn connect<A: ToSocketAddrs>(address: A, connect_timeout: Duration) -> Result<Self, TransportError> {
+        let mut last_error : TransportError = TransportError::Closed;
+        for addr in address.to_socket_addrs()? {
+            match Self::connect_addr(&addr, connect_timeout) {
+                Ok(transport) => return Ok(transport),
+                Err(e)        => last_error = e
+            }
+        }
+        Err(last_error)
+    }
+
+    pub fn connect_default() -> Result<Self, TransportError> {
+        Self::connect(DEFAULT_ADDRESS, DEFAULT_CONNECT_TIMEOUT)
+    }
+
+    pub fn from_stream(stream: TcpStream) -> Result<Self, TransportError> {
+        stream.set_nodelay(true)?;
+        Ok(TcpTransport { stream, timeout: DEFAULT_TIMEOUT, pending: vec![] })
+    }
+
+    fn connect_addr(addr: &SocketAddr, connect_timeout: Duration) -> Result<Self, TransportError> {
+        Self::from_stream(TcpStream::connect_timeout(addr, connect_timeout)?)
+    }
+}
+
+impl Transport for TcpTransport {
+    fn write_command(&mut self, command: &str) -> Result<(), TransportError> {
+        self.stream.set_write_timeout(Some(self.timeout))?;
+        self.stream.write_all(&encode_command(command))?;
+        self.stream.flush()?;
+        Ok(())
+    }
+
+    fn read_until_prompt(&mut self) -> Result<String, TransportError> {
+        let deadline : Instant   = Instant::now() + self.timeout;
+        let mut buf  : [u8; 256] = [0; 256];
+        loop {
+            if let Some(response) = take_response(&mut self.pending) { return Ok(response); }
+            let now : Instant = Instant::now();
+            if now >= deadline { return Err(TransportError::Timeout); }
+            self.stream.set_read_timeout(Some(deadline - now))?;
+            match self.stream.read(&mut buf) {
+                Ok(0)  => return Err(TransportError::Closed),
+                Ok(n)  => self.pending.extend_from_slice(&buf[..n]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into())
+            }
+        }
+    }
+
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn fake_dongle<F: FnOnce(TcpStream) + Send + 'static>(adapter: F) -> (TcpTransport, thread::JoinHandle<()>) {
+        //
+        // Adaptateur simulé sur 127.0.0.1, port attribué par le système
+        //
+        let listener : TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address  : SocketAddr  = listener.local_addr().unwrap();
+        let handle = thread::spawn(move || adapter(listener.accept().unwrap().0));
+        let mut transport : TcpTransport = TcpTransport::connect(address, DEFAULT_CONNECT_TIMEOUT).unwrap();
+        transport.set_timeout(Duration::from_millis(200));
+        (transport, handle)
+    }
+
+    #[test]
+    fn send_reads_reply_up_to_prompt() {
+        let (mut transport, handle) = fake_dongle(|mut stream| {
+            let mut command : [u8; 5] = [0; 5];
+            stream.read_exact(&mut command).unwrap();
+            assert_eq!(&command, b"010D\r");
+            stream.write_all(b"41 0D").unwrap();
+            stream.flush().unwrap();
+            thread::sleep(Duration::from_millis(20));
+            stream.write_all(b" 32\r\r>").unwrap();
+        });
+        assert_eq!(transport.send("010D").unwrap(), "41 0D 32\r\r");
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn read_without_prompt_times_out() {
+        let (mut transport, handle) = fake_dongle(|mut stream| {
+            stream.write_all(b"41 0D 32").unwrap();
+            thread::sleep(Duration::from_millis(400));
+        });
+        let start : Instant = Instant::now();
+        assert!(matches!(transport.read_until_prompt(), Err(TransportError::Timeout)));
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn peer_hang_up_is_reported() {
+        let (mut transport, handle) = fake_dongle(|mut stream| {
+            stream.write_all(b"41 0D").unwrap();
+        });
+        handle.join().unwrap();
+        assert!(matches!(transport.read_until_prompt(), Err(TransportError::Closed)));
+    }
+}
@@ -0,0 +1,99 @@
+// Uses
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+// Constantes
+pub const PROMPT          : u8       = b'>';
+pub const DEFAULT_TIMEOUT : Duration = Duration::from_secs(5);
+
+// Enums
+#[derive(Debug)]
+pub enum TransportError {
+    Io(io::Error),
+    Timeout,
+    Closed,
+    InvalidBaudRate(u32)
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransportError::Io(e)              => write!(f, "Erreur d'entrée/sortie : {}", e),
+            TransportError::Timeout            => write!(f, "Délai dépassé en attendant le prompt '>'"),
+            TransportError::Closed             => write!(f, "Connexion avec l'adaptateur fermée"),
+            TransportError::InvalidBaudRate(b) => write!(f, "Vitesse de transmission non supportée : {}", b)
+        }
+    }
+}
+
+impl std::error::Error for TransportError {}
+
+impl From<io::Error> for TransportError {
+    fn from(e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => TransportError::Timeout,
+            _                                                   => TransportError::Io(e)
+        }
+    }
+}
+
+// Définition d'un moyen de transport vers un elm327
+pub trait Transport {
+    //
+    // Trait définissant ce que doit implémenter un lien vers un elm327
+    // :fn write_command:     Méthode envoyant une commande, le '\r' final est ajouté
+    // :fn read_until_prompt: Méthode lisant la réponse jusqu'au prompt '>' (exclu), les octets reçus après le prompt
+    //                        étant conservés pour la commande suivante
+    // :fn timeout:           Délai maximal d'attente du prompt
+    // :fn set_timeout:       Méthode modifiant le délai maximal d'attente du prompt
+    // :fn send:              Méthode envoyant une commande et retournant sa réponse
+    //
+    fn write_command(&mut self, command: &str) -> Result<(), TransportError>;
+    fn read_until_prompt(&mut self) -> Result<String, TransportError>;
+    fn timeout(&self) -> Duration;
+    fn set_timeout(&mut self, timeout: Duration);
+    fn send(&mut self, command: &str) -> Result<String, TransportError> {
+        self.write_command(command)?;
+        self.read_until_prompt()
+    }
+}
+
+// Fonctions
+pub fn encode_command(command: &str) -> Vec<u8> {
+    let mut encoded : Vec<u8> = command.trim_end_matches(['\r', '\n']).as_bytes().to_vec();
+    encoded.push(b'\r');
+    encoded
+}
+
+pub fn take_response(pending: &mut Vec<u8>) -> Option<String> {
+    //
+    // Retire du tampon la réponse terminée par le premier prompt, le reste du tampon étant laissé en place
+    // :return: None si aucun prompt n'a encore été reçu
+    //
+    let end : usize   = pending.iter().position(|b| *b == PROMPT)?;
+    let raw : Vec<u8> = pending.drain(..=end).collect();
+    Some(decode_response(&raw[..end]))
+}
+
+pub fn decode_response(raw: &[u8]) -> String {
+    raw.iter()
+        .filter(|b| **b != 0x00)
+        .map(|b| *b as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_after_prompt_stay_pending() {
+        let mut pending : Vec<u8> = b"41 0D 32\r\r>41 0C\r\0>\r".to_vec();
+        assert_eq!(take_response(&mut pending), Some("41 0D 32\r\r".to_string()));
+        assert_eq!(take_response(&mut pending), Some("41 0C\r".to_string()));
+        assert_eq!(take_response(&mut pending), None);
+        assert_eq!(pending, b"\r");
+        assert_eq!(encode_command("ATZ\r\n"), b"ATZ\r");
+    }
+}
@@ -1,3 +1,4 @@
 // Types
-pub type ModLen = usize;
-pub type PidLen = usize;
\ No newline at end of file
+pub type ModLen     = usize;
+pub type PidLen     = usize;
+pub type EcuAddress = u32;
\ No newline at end of file
@@ -1 +1,3 @@
+#![allow(clippy::new_without_default)]
+
 pub mod elm327;
\ No newline at end of file